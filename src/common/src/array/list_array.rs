@@ -456,7 +456,7 @@ impl<'a> ListRef<'a> {
     pub fn value_at(&self, index: usize) -> ArrayResult<DatumRef<'a>> {
         match self {
             ListRef::Indexed { arr, idx } => {
-                if index <= arr.value.len() {
+                if index <= arr.offsets[*idx + 1] - arr.offsets[*idx] {
                     Ok(arr.value.value_at(arr.offsets[*idx] + index - 1))
                 } else {
                     Ok(None)
@@ -165,6 +165,11 @@ pub struct StorageConfig {
     #[serde(default = "default::write_conflict_detection_enabled")]
     pub write_conflict_detection_enabled: bool,
 
+    /// How to handle write conflicts. Takes precedence over `write_conflict_detection_enabled` if
+    /// set.
+    #[serde(default)]
+    pub write_conflict_detection_mode: Option<WriteConflictDetectionMode>,
+
     /// Capacity of sstable block cache.
     #[serde(default = "default::block_cache_capacity_mb")]
     pub block_cache_capacity_mb: usize,
@@ -213,6 +218,32 @@ impl Default for StorageConfig {
     }
 }
 
+impl StorageConfig {
+    /// Returns how write conflicts are handled. If `write_conflict_detection_mode` is not set,
+    /// `write_conflict_detection_enabled` maps to `Panic`, or `Off` if it is false.
+    pub fn write_conflict_detection_mode(&self) -> WriteConflictDetectionMode {
+        match self.write_conflict_detection_mode {
+            Some(mode) => mode,
+            None if self.write_conflict_detection_enabled => WriteConflictDetectionMode::Panic,
+            None => WriteConflictDetectionMode::Off,
+        }
+    }
+}
+
+/// What the storage does when a key is written again with a different value in the same epoch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteConflictDetectionMode {
+    /// Conflicts are not detected.
+    Off,
+    /// Conflicts are logged as warnings and the last write wins.
+    Warn,
+    /// Conflicting writes are rejected with an error.
+    Error,
+    /// Conflicting writes panic.
+    Panic,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct FileCacheConfig {
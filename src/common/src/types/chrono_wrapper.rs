@@ -248,7 +248,7 @@ impl CheckedAdd<IntervalUnit> for NaiveDateTimeWrapper {
             // Fix the days after changing date.
             // For example, 1970.1.31 + 1 month = 1970.2.28
             day = day.min(get_mouth_days(year, month as usize));
-            date = NaiveDate::from_ymd(year, month as u32, day as u32);
+            date = NaiveDate::from_ymd_opt(year, month as u32, day as u32)?;
         }
         let mut datetime = NaiveDateTime::new(date, self.0.time());
         datetime = datetime.checked_add_signed(Duration::days(rhs.get_days().into()))?;
@@ -414,6 +414,31 @@ impl Decimal {
         }
     }
 
+    /// Rounds towards positive infinity, keeping `dp` decimal places.
+    #[must_use]
+    pub fn ceil_dp(&self, dp: u32) -> Self {
+        self.round_dp_with_strategy(dp, RoundingStrategy::ToPositiveInfinity)
+    }
+
+    /// Rounds towards negative infinity, keeping `dp` decimal places.
+    #[must_use]
+    pub fn floor_dp(&self, dp: u32) -> Self {
+        self.round_dp_with_strategy(dp, RoundingStrategy::ToNegativeInfinity)
+    }
+
+    /// Rounds towards zero, keeping `dp` decimal places.
+    #[must_use]
+    pub fn trunc_dp(&self, dp: u32) -> Self {
+        self.round_dp_with_strategy(dp, RoundingStrategy::ToZero)
+    }
+
+    fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Self {
+        match self {
+            Self::Normalized(d) => Self::Normalized(d.round_dp_with_strategy(dp, strategy)),
+            d => *d,
+        }
+    }
+
     #[must_use]
     pub fn ceil(&self) -> Self {
         match self {
@@ -536,6 +561,7 @@ impl FromStr for Decimal {
             "nan" | "NaN" | "NAN" => Ok(Decimal::NaN),
             "inf" | "INF" | "+inf" | "+INF" | "+Inf" => Ok(Decimal::PositiveInf),
             "-inf" | "-INF" | "-Inf" => Ok(Decimal::NegativeInf),
+            s if s.contains(['e', 'E']) => RustDecimal::from_scientific(s).map(Decimal::Normalized),
             s => RustDecimal::from_str(s).map(Decimal::Normalized),
         }
     }
@@ -79,6 +79,24 @@ pub fn new_repeat(
         .boxed()
 }
 
+pub fn new_left(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    BinaryBytesExpression::<Utf8Array, I32Array, _>::new(expr_ia1, expr_ia2, return_type, left)
+        .boxed()
+}
+
+pub fn new_right(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    BinaryBytesExpression::<Utf8Array, I32Array, _>::new(expr_ia1, expr_ia2, return_type, right)
+        .boxed()
+}
+
 macro_rules! impl_utf8_utf8 {
     ($({ $func_name:ident, $method:ident }),*) => {
         $(pub fn $func_name(
@@ -177,4 +195,24 @@ mod tests {
             create_str_i32_binary_expr(new_substr_for, None, Some(ScalarImpl::Int32(for_pos)));
         test_evals_dummy(&substr_for_str_none, None);
     }
+
+    #[test]
+    fn test_left_right() {
+        let text = || Some(ScalarImpl::from(String::from("quick brown")));
+        let cases = [
+            (new_left as fn(_, _, _) -> _, 5, "quick"),
+            (new_left, -6, "quick"),
+            (new_right, 5, "brown"),
+            (new_right, -6, "brown"),
+        ];
+        for (f, n, expected) in cases {
+            let expr = create_str_i32_binary_expr(f, text(), Some(ScalarImpl::Int32(n)));
+            test_evals_dummy(&expr, Some(ScalarImpl::from(String::from(expected))));
+        }
+        test_evals_dummy(&create_str_i32_binary_expr(new_left, text(), None), None);
+        test_evals_dummy(
+            &create_str_i32_binary_expr(new_right, None, Some(ScalarImpl::Int32(1))),
+            None,
+        );
+    }
 }
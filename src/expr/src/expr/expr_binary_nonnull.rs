@@ -13,22 +13,26 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, IntervalArray, ListArray, NaiveDateArray,
+    Array, BoolArray, DecimalArray, F64Array, I32Array, IntervalArray, ListArray, NaiveDateArray,
     NaiveDateTimeArray, StructArray, Utf8Array,
 };
 use risingwave_common::types::*;
 use risingwave_pb::expr::expr_node::Type;
 
-use crate::expr::expr_binary_bytes::new_concat_op;
+use crate::expr::expr_binary_bytes::{new_concat_op, new_left, new_right};
 use crate::expr::template::BinaryExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::*;
 use crate::vector_op::bitwise_op::*;
 use crate::vector_op::cmp::*;
+use crate::vector_op::date_trunc::{date_trunc_interval_str, date_trunc_timestamp_str};
 use crate::vector_op::extract::{extract_from_date, extract_from_timestamp};
+use crate::vector_op::gcd_lcm::{general_gcd, general_lcm};
 use crate::vector_op::like::like_default;
-use crate::vector_op::position::position;
+use crate::vector_op::position::{position, starts_with};
 use crate::vector_op::round::round_digits;
+use crate::vector_op::to_char::{to_date, to_timestamp};
+use crate::vector_op::trigonometric::atan2_f64;
 use crate::vector_op::tumble::{tumble_start_date, tumble_start_date_time};
 use crate::{for_all_cmp_variants, ExprError, Result};
 
@@ -344,6 +348,34 @@ fn build_extract_expr(
     Ok(expr)
 }
 
+fn build_date_trunc_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> Result<BoxedExpression> {
+    let expr: BoxedExpression = match r.return_type() {
+        DataType::Timestamp => Box::new(BinaryExpression::<
+            Utf8Array,
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, date_trunc_timestamp_str)),
+        DataType::Interval => Box::new(BinaryExpression::<
+            Utf8Array,
+            IntervalArray,
+            IntervalArray,
+            _,
+        >::new(l, r, ret, date_trunc_interval_str)),
+        _ => {
+            return Err(ExprError::UnsupportedFunction(format!(
+                "DateTrunc ( {:?} ) is not supported yet!",
+                r.return_type()
+            )))
+        }
+    };
+    Ok(expr)
+}
+
 pub fn new_binary_expr(
     expr_type: Type,
     ret: DataType,
@@ -501,7 +533,32 @@ pub fn new_binary_expr(
                 },
             }
         }
+        Type::Gcd => {
+            gen_binary_expr_bitwise! {
+                gen_atm_impl,
+                l, r, ret,
+                general_gcd,
+                {
+                },
+            }
+        }
+        Type::Lcm => {
+            gen_binary_expr_bitwise! {
+                gen_atm_impl,
+                l, r, ret,
+                general_lcm,
+                {
+                },
+            }
+        }
+        Type::Pow => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, pow_f64,
+        )),
+        Type::Atan2 => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, atan2_f64,
+        )),
         Type::Extract => build_extract_expr(ret, l, r)?,
+        Type::DateTrunc => build_date_trunc_expr(ret, l, r)?,
         Type::RoundDigit => Box::new(
             BinaryExpression::<DecimalArray, I32Array, DecimalArray, _>::new(
                 l,
@@ -513,8 +570,25 @@ pub fn new_binary_expr(
         Type::Position => Box::new(BinaryExpression::<Utf8Array, Utf8Array, I32Array, _>::new(
             l, r, ret, position,
         )),
+        Type::StartsWith => Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+            l,
+            r,
+            ret,
+            starts_with,
+        )),
+        Type::ToDate => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, NaiveDateArray, _>::new(l, r, ret, to_date),
+        ),
+        Type::ToTimestamp => Box::new(BinaryExpression::<
+            Utf8Array,
+            Utf8Array,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, to_timestamp)),
         Type::TumbleStart => new_tumble_start(l, r, ret)?,
         Type::ConcatOp => new_concat_op(l, r, ret),
+        Type::Left => new_left(l, r, ret),
+        Type::Right => new_right(l, r, ret),
 
         tp => {
             return Err(ExprError::UnsupportedFunction(format!(
@@ -21,6 +21,7 @@ use risingwave_pb::expr::expr_node::Type;
 use super::BoxedExpression;
 use crate::expr::template::BinaryNullableExpression;
 use crate::vector_op::array_access::array_access;
+use crate::vector_op::array_length::array_length;
 use crate::vector_op::cmp::{
     general_is_distinct_from, general_is_not_distinct_from, str_is_distinct_from,
     str_is_not_distinct_from,
@@ -70,6 +71,7 @@ pub fn new_nullable_binary_expr(
 ) -> Result<BoxedExpression> {
     let expr = match expr_type {
         Type::ArrayAccess => build_array_access_expr(ret, l, r),
+        Type::ArrayLength => build_array_length_expr(ret, l, r),
         Type::And => Box::new(
             BinaryNullableExpression::<BoolArray, BoolArray, BoolArray, _>::new(l, r, ret, and),
         ),
@@ -127,6 +129,16 @@ fn build_array_access_expr(
     }
 }
 
+fn build_array_length_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> BoxedExpression {
+    Box::new(
+        BinaryNullableExpression::<ListArray, I32Array, I32Array, _>::new(l, r, ret, array_length),
+    )
+}
+
 pub fn new_distinct_from_expr(
     l: BoxedExpression,
     r: BoxedExpression,
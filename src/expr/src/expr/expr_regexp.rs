@@ -18,13 +18,14 @@ use itertools::Itertools;
 use regex::Regex;
 use risingwave_common::array::{
     Array, ArrayBuilder, ArrayMeta, ArrayRef, DataChunk, ListArrayBuilder, ListRef, ListValue, Row,
-    Utf8Array,
+    Utf8Array, Utf8ArrayBuilder,
 };
 use risingwave_common::types::{DataType, Datum, Scalar, ScalarImpl};
 use risingwave_pb::expr::expr_node::{RexNode, Type};
 use risingwave_pb::expr::ExprNode;
 
 use super::{build_from_prost as expr_build_from_prost, Expression};
+use crate::vector_op::regexp_matches::regex_builder_with_flags;
 use crate::{bail, ensure, ExprError, Result};
 
 #[derive(Debug)]
@@ -151,3 +152,187 @@ impl Expression for RegexpMatchExpression {
         })
     }
 }
+
+/// Returns the value of a constant `Varchar` argument of a regular expression function.
+fn constant_utf8(node: &ExprNode, name: &str) -> Result<String> {
+    let RexNode::Constant(value) = node.get_rex_node().unwrap() else {
+        return Err(ExprError::UnsupportedFunction(format!(
+            "non-constant {} in regexp_replace",
+            name
+        )));
+    };
+    let ScalarImpl::Utf8(value) =
+        ScalarImpl::from_proto_bytes(value.get_body(), node.get_return_type().unwrap())?
+    else {
+        bail!("Expected {} to be an String", name);
+    };
+    Ok(value)
+}
+
+/// Converts a `PostgreSQL` replacement string, where `\n` refers to the `n`-th captured group and
+/// `\&` to the whole match, into the syntax of [`Regex::replace`].
+fn convert_replacement(replacement: &str) -> String {
+    let mut converted = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '$' => converted.push_str("$$"),
+            '\\' => match chars.next() {
+                Some(n @ '0'..='9') => {
+                    converted.push_str("${");
+                    converted.push(n);
+                    converted.push('}');
+                }
+                Some('&') => converted.push_str("${0}"),
+                Some('\\') => converted.push('\\'),
+                Some(c) => {
+                    converted.push('\\');
+                    converted.push(c);
+                }
+                None => converted.push('\\'),
+            },
+            c => converted.push(c),
+        }
+    }
+    converted
+}
+
+/// `regexp_replace(text, pattern, replacement [, flags])`. The pattern, replacement and flags must
+/// be constants.
+#[derive(Debug)]
+pub struct RegexpReplaceExpression {
+    pub child: Box<dyn Expression>,
+    pub ctx: RegexpContext,
+    pub replacement: String,
+    /// Whether to replace all matches rather than only the first one, i.e. the `g` flag.
+    pub global: bool,
+}
+
+impl<'a> TryFrom<&'a ExprNode> for RegexpReplaceExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type().unwrap() == Type::RegexpReplace);
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node().unwrap() else {
+            bail!("Expected RexNode::FuncCall");
+        };
+        let mut children = func_call_node.children.iter();
+        let Some(text_node) = children.next() else {
+            bail!("Expected argument text");
+        };
+        let text_expr = expr_build_from_prost(text_node)?;
+        let Some(pattern_node) = children.next() else {
+            bail!("Expected argument pattern");
+        };
+        let pattern = constant_utf8(pattern_node, "pattern")?;
+        let Some(replacement_node) = children.next() else {
+            bail!("Expected argument replacement");
+        };
+        let replacement = constant_utf8(replacement_node, "replacement")?;
+        let flags = match children.next() {
+            Some(flags_node) => constant_utf8(flags_node, "flags")?,
+            None => String::new(),
+        };
+        Self::new(text_expr, &pattern, &replacement, &flags)
+    }
+}
+
+impl RegexpReplaceExpression {
+    pub fn new(
+        child: Box<dyn Expression>,
+        pattern: &str,
+        replacement: &str,
+        flags: &str,
+    ) -> Result<Self> {
+        let (builder, global) = regex_builder_with_flags(pattern, flags)?;
+        Ok(Self {
+            child,
+            ctx: RegexpContext(builder.build()?),
+            replacement: convert_replacement(replacement),
+            global,
+        })
+    }
+
+    fn replace_one<'a>(&self, text: &'a str) -> std::borrow::Cow<'a, str> {
+        if self.global {
+            self.ctx.0.replace_all(text, self.replacement.as_str())
+        } else {
+            self.ctx.0.replace(text, self.replacement.as_str())
+        }
+    }
+}
+
+impl Expression for RegexpReplaceExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Varchar
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let text_arr = self.child.eval_checked(input)?;
+        let text_arr: &Utf8Array = text_arr.as_ref().into();
+        let mut output = Utf8ArrayBuilder::new(input.capacity());
+
+        for (text, vis) in text_arr.iter().zip_eq(input.vis().iter()) {
+            match text {
+                Some(text) if vis => output.append(Some(self.replace_one(text).as_ref())),
+                _ => output.append(None),
+            }
+        }
+
+        Ok(Arc::new(output.finish().into()))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let text = self.child.eval_row(input)?;
+        Ok(if let Some(ScalarImpl::Utf8(text)) = text {
+            Some(self.replace_one(&text).into_owned().to_scalar_value())
+        } else {
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    fn regexp_replace(text: &str, pattern: &str, replacement: &str, flags: &str) -> Result<Datum> {
+        RegexpReplaceExpression::new(
+            Box::new(LiteralExpression::new(
+                DataType::Varchar,
+                Some(ScalarImpl::Utf8(text.to_string())),
+            )),
+            pattern,
+            replacement,
+            flags,
+        )?
+        .eval_row(&Row::new(vec![]))
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let cases = [
+            ("foobarbaz", "b..", "X", "", "fooXbaz"),
+            ("foobarbaz", "b..", "X", "g", "fooXX"),
+            ("foobarbaz", "B..", "X", "gi", "fooXX"),
+            ("foobarbaz", "b(.)(.)", "\\2\\1", "g", "fooraza"),
+            ("foobarbaz", "b..", "<\\&>", "", "foo<bar>baz"),
+            ("abc", "b", "$1\\\\", "", "a$1\\c"),
+            ("abc", "x", "y", "g", "abc"),
+        ];
+        for (text, pattern, replacement, flags, expected) in cases {
+            assert_eq!(
+                regexp_replace(text, pattern, replacement, flags).unwrap(),
+                Some(ScalarImpl::Utf8(expected.to_string())),
+                "regexp_replace({:?}, {:?}, {:?}, {:?})",
+                text,
+                pattern,
+                replacement,
+                flags
+            );
+        }
+        assert!(regexp_replace("abc", "b", "x", "q").is_err());
+        assert!(regexp_replace("abc", "(b", "x", "").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, Row};
+use risingwave_common::types::{
+    to_datum_ref, DataType, Datum, DatumRef, ScalarImpl, ScalarRefImpl,
+};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::string_to_array::{array_to_string, string_to_array};
+use crate::{bail, ensure, ExprError, Result};
+
+fn eval_string_to_array(inputs: &[DatumRef<'_>]) -> Datum {
+    let Some(ScalarRefImpl::Utf8(s)) = inputs[0] else {
+        return None;
+    };
+    let delim = match inputs[1] {
+        Some(ScalarRefImpl::Utf8(delim)) => Some(delim),
+        _ => None,
+    };
+    let null_string = match inputs.get(2) {
+        Some(Some(ScalarRefImpl::Utf8(null_string))) => Some(*null_string),
+        _ => None,
+    };
+    Some(ScalarImpl::List(string_to_array(s, delim, null_string)))
+}
+
+fn eval_array_to_string(inputs: &[DatumRef<'_>]) -> Datum {
+    let (Some(ScalarRefImpl::List(list)), Some(ScalarRefImpl::Utf8(delim))) =
+        (inputs[0], inputs[1])
+    else {
+        return None;
+    };
+    let null_string = match inputs.get(2) {
+        Some(Some(ScalarRefImpl::Utf8(null_string))) => Some(*null_string),
+        _ => None,
+    };
+    Some(ScalarImpl::Utf8(array_to_string(list, delim, null_string)))
+}
+
+/// Implements `string_to_array(s, delim [, null_string])` and
+/// `array_to_string(arr, delim [, null_string])`, which only differ in how a row is evaluated.
+#[derive(Debug)]
+pub struct StringToArrayExpression {
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+    func: fn(&[DatumRef<'_>]) -> Datum,
+}
+
+impl StringToArrayExpression {
+    fn new(
+        return_type: DataType,
+        children: Vec<BoxedExpression>,
+        func: fn(&[DatumRef<'_>]) -> Datum,
+    ) -> Self {
+        Self {
+            return_type,
+            children,
+            func,
+        }
+    }
+}
+
+impl Expression for StringToArrayExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays = self
+            .children
+            .iter()
+            .map(|c| c.eval_checked(input))
+            .collect::<Result<Vec<_>>>()?;
+        let vis = input.vis();
+        let mut builder = self.return_type.create_array_builder(input.capacity());
+        let mut datums = Vec::with_capacity(arrays.len());
+        for row_idx in 0..input.capacity() {
+            if !vis.is_set(row_idx) {
+                builder.append_null();
+                continue;
+            }
+            datums.clear();
+            datums.extend(arrays.iter().map(|a| a.value_at(row_idx)));
+            builder.append_datum(&(self.func)(&datums));
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let data = self
+            .children
+            .iter()
+            .map(|c| c.eval_row(input))
+            .collect::<Result<Vec<_>>>()?;
+        let datums = data.iter().map(to_datum_ref).collect::<Vec<_>>();
+        Ok((self.func)(&datums))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for StringToArrayExpression {
+    type Error = ExprError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let func: fn(&[DatumRef<'_>]) -> Datum = match prost.get_expr_type()? {
+            Type::StringToArray => eval_string_to_array,
+            Type::ArrayToString => eval_array_to_string,
+            _ => bail!("expects `StringToArray`|`ArrayToString`"),
+        };
+        let RexNode::FuncCall(func_call_node) = prost.get_rex_node()? else {
+            bail!("expects a RexNode::FuncCall");
+        };
+        let children = func_call_node.get_children();
+        ensure!((2..=3).contains(&children.len()));
+        let children = children
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?;
+        let ret_type = DataType::from(prost.get_return_type()?);
+        Ok(Self::new(ret_type, children, func))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{DataChunk, ListValue};
+    use risingwave_pb::expr::expr_node::Type as ProstType;
+    use risingwave_pb::expr::FunctionCall;
+
+    use super::*;
+    use crate::expr::test_utils::make_string_literal;
+
+    fn make_func(expr_type: ProstType, return_type: DataType, children: Vec<ExprNode>) -> ExprNode {
+        ExprNode {
+            expr_type: expr_type as i32,
+            return_type: Some(return_type.to_protobuf()),
+            rex_node: Some(RexNode::FuncCall(FunctionCall { children })),
+        }
+    }
+
+    fn varchar_list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| ScalarImpl::Utf8(v.into())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_string_to_array_and_back() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Varchar),
+        };
+        let string_to_array = make_func(
+            ProstType::StringToArray,
+            list_type.clone(),
+            vec![
+                make_string_literal("a,*,c"),
+                make_string_literal(","),
+                make_string_literal("*"),
+            ],
+        );
+        let expr = StringToArrayExpression::try_from(&string_to_array).unwrap();
+        let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+        assert_eq!(
+            res.datum_at(0),
+            Some(ScalarImpl::List(varchar_list(&[
+                Some("a"),
+                None,
+                Some("c")
+            ])))
+        );
+
+        let array_to_string = make_func(
+            ProstType::ArrayToString,
+            DataType::Varchar,
+            vec![
+                string_to_array,
+                make_string_literal("|"),
+                make_string_literal("NULL"),
+            ],
+        );
+        let expr = StringToArrayExpression::try_from(&array_to_string).unwrap();
+        assert_eq!(
+            expr.eval_row(&Row::new(vec![])).unwrap(),
+            Some(ScalarImpl::Utf8("a|NULL|c".into()))
+        );
+    }
+}
@@ -22,18 +22,22 @@ use super::template::{UnaryBytesExpression, UnaryExpression};
 use crate::expr::expr_is_null::{IsNotNullExpression, IsNullExpression};
 use crate::expr::template::UnaryNullableExpression;
 use crate::expr::BoxedExpression;
-use crate::vector_op::arithmetic_op::{decimal_abs, general_abs, general_neg};
-use crate::vector_op::ascii::ascii;
+use crate::vector_op::arithmetic_op::{decimal_abs, general_abs, general_neg, sqrt_f64};
+use crate::vector_op::array_length::cardinality;
+use crate::vector_op::ascii::{ascii, chr};
 use crate::vector_op::bitwise_op::general_bitnot;
 use crate::vector_op::cast::*;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
+use crate::vector_op::gcd_lcm::factorial;
 use crate::vector_op::length::{bit_length, length_default, octet_length};
 use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
 use crate::vector_op::md5::md5;
+use crate::vector_op::reverse::reverse;
 use crate::vector_op::round::*;
 use crate::vector_op::rtrim::rtrim;
+use crate::vector_op::trigonometric::*;
 use crate::vector_op::trim::trim;
 use crate::vector_op::upper::upper;
 use crate::{for_all_cast_variants, ExprError, Result};
@@ -138,6 +142,20 @@ pub fn new_unary_expr(
             return_type,
             move |input| list_cast(input, &source_elem_type, &target_elem_type),
         )),
+        (ProstType::Cast, DataType::Struct(target_type), DataType::Varchar) => {
+            Box::new(UnaryExpression::<Utf8Array, StructArray, _>::new(
+                child_expr,
+                return_type,
+                move |input| str_to_struct(input, &target_type.fields),
+            ))
+        }
+        (ProstType::Cast, DataType::Varchar, DataType::Struct(_)) => {
+            Box::new(UnaryExpression::<StructArray, Utf8Array, _>::new(
+                child_expr,
+                return_type,
+                struct_to_string,
+            ))
+        }
         (ProstType::Cast, _, _) => {
             macro_rules! gen_cast_impl {
                 ($( { $input:ident, $cast:ident, $func:expr } ),*) => {
@@ -219,11 +237,26 @@ pub fn new_unary_expr(
             return_type,
             md5,
         )),
+        (ProstType::Reverse, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            reverse,
+        )),
         (ProstType::Ascii, _, _) => Box::new(UnaryExpression::<Utf8Array, I32Array, _>::new(
             child_expr,
             return_type,
             ascii,
         )),
+        (ProstType::Chr, _, _) => Box::new(UnaryBytesExpression::<I32Array, _>::new(
+            child_expr,
+            return_type,
+            chr,
+        )),
+        (ProstType::Cardinality, _, _) => Box::new(UnaryExpression::<ListArray, I32Array, _>::new(
+            child_expr,
+            return_type,
+            cardinality,
+        )),
         (ProstType::CharLength, _, _) => Box::new(UnaryExpression::<Utf8Array, I32Array, _>::new(
             child_expr,
             return_type,
@@ -253,6 +286,46 @@ pub fn new_unary_expr(
                 }
             }
         }
+        (ProstType::Factorial, _, _) => Box::new(UnaryExpression::<I64Array, I64Array, _>::new(
+            child_expr,
+            return_type,
+            factorial,
+        )),
+        (ProstType::Sqrt, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            sqrt_f64,
+        )),
+        (ProstType::Sin, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            sin_f64,
+        )),
+        (ProstType::Cos, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            cos_f64,
+        )),
+        (ProstType::Tan, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            tan_f64,
+        )),
+        (ProstType::Asin, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            asin_f64,
+        )),
+        (ProstType::Acos, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            acos_f64,
+        )),
+        (ProstType::Atan, _, _) => Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+            child_expr,
+            return_type,
+            atan_f64,
+        )),
         (ProstType::BitwiseNot, _, _) => {
             gen_unary_impl! {
                 [ "BitwiseNot", child_expr, return_type],
@@ -23,6 +23,7 @@ mod expr_case;
 mod expr_coalesce;
 mod expr_concat_ws;
 mod expr_field;
+mod expr_format;
 mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
@@ -30,6 +31,7 @@ mod expr_literal;
 mod expr_nested_construct;
 mod expr_quaternary_bytes;
 mod expr_regexp;
+mod expr_string_to_array;
 mod expr_ternary_bytes;
 mod expr_to_char_const_tmpl;
 pub mod expr_unary;
@@ -53,9 +55,11 @@ use crate::expr::expr_case::CaseExpression;
 use crate::expr::expr_coalesce::CoalesceExpression;
 use crate::expr::expr_concat_ws::ConcatWsExpression;
 use crate::expr::expr_field::FieldExpression;
+use crate::expr::expr_format::FormatExpression;
 use crate::expr::expr_in::InExpression;
 use crate::expr::expr_nested_construct::NestedConstructExpression;
-use crate::expr::expr_regexp::RegexpMatchExpression;
+use crate::expr::expr_regexp::{RegexpMatchExpression, RegexpReplaceExpression};
+use crate::expr::expr_string_to_array::StringToArrayExpression;
 use crate::expr::expr_vnode::VnodeExpression;
 use crate::ExprError;
 
@@ -102,12 +106,14 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         // Fixed number of arguments and based on `Unary/Binary/Ternary/...Expression`
         Cast | Upper | Lower | Md5 | Not | IsTrue | IsNotTrue | IsFalse | IsNotFalse | IsNull
         | IsNotNull | Neg | Ascii | Abs | Ceil | Floor | Round | BitwiseNot | CharLength
-        | BoolOut | OctetLength | BitLength => build_unary_expr_prost(prost),
+        | BoolOut | OctetLength | BitLength | Factorial | Sqrt | Chr | Sin | Cos | Tan | Asin
+        | Acos | Atan | Reverse | Cardinality => build_unary_expr_prost(prost),
         Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual | Add
-        | Subtract | Multiply | Divide | Modulus | Extract | RoundDigit | TumbleStart
-        | Position | BitwiseShiftLeft | BitwiseShiftRight | BitwiseAnd | BitwiseOr | BitwiseXor
-        | ConcatOp => build_binary_expr_prost(prost),
-        And | Or | IsDistinctFrom | IsNotDistinctFrom | ArrayAccess => {
+        | Subtract | Multiply | Divide | Modulus | Extract | DateTrunc | RoundDigit
+        | TumbleStart | Position | BitwiseShiftLeft | BitwiseShiftRight | BitwiseAnd
+        | BitwiseOr | BitwiseXor | ConcatOp | Gcd | Lcm | Pow | Atan2 | Left | Right | ToDate
+        | ToTimestamp | StartsWith => build_binary_expr_prost(prost),
+        And | Or | IsDistinctFrom | IsNotDistinctFrom | ArrayAccess | ArrayLength => {
             build_nullable_binary_expr_prost(prost)
         }
         ToChar => build_to_char_expr(prost),
@@ -130,18 +136,23 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         Case => CaseExpression::try_from(prost).map(Expression::boxed),
         Coalesce => CoalesceExpression::try_from(prost).map(Expression::boxed),
         ConcatWs => ConcatWsExpression::try_from(prost).map(Expression::boxed),
+        Format => FormatExpression::try_from(prost).map(Expression::boxed),
         ConstantValue => LiteralExpression::try_from(prost).map(Expression::boxed),
         InputRef => InputRefExpression::try_from(prost).map(Expression::boxed),
         Field => FieldExpression::try_from(prost).map(Expression::boxed),
         Array => NestedConstructExpression::try_from(prost).map(Expression::boxed),
         Row => NestedConstructExpression::try_from(prost).map(Expression::boxed),
         RegexpMatch => RegexpMatchExpression::try_from(prost).map(Expression::boxed),
+        RegexpReplace => RegexpReplaceExpression::try_from(prost).map(Expression::boxed),
         ArrayCat | ArrayAppend | ArrayPrepend => {
             // Now we implement these three functions as a single expression for the
             // sake of simplicity. If performance matters at some time, we can split
             // the implementation to improve performance.
             ArrayConcatExpression::try_from(prost).map(Expression::boxed)
         }
+        StringToArray | ArrayToString => {
+            StringToArrayExpression::try_from(prost).map(Expression::boxed)
+        }
         Vnode => VnodeExpression::try_from(prost).map(Expression::boxed),
         _ => Err(ExprError::UnsupportedFunction(format!(
             "{:?}",
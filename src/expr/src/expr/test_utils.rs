@@ -60,6 +60,19 @@ pub fn make_i32_literal(data: i32) -> ExprNode {
     }
 }
 
+pub fn make_string_literal(s: &str) -> ExprNode {
+    ExprNode {
+        expr_type: Type::ConstantValue as i32,
+        return_type: Some(ProstDataType {
+            type_name: TypeName::Varchar as i32,
+            ..Default::default()
+        }),
+        rex_node: Some(RexNode::Constant(ConstantValue {
+            body: s.as_bytes().to_vec(),
+        })),
+    }
+}
+
 pub fn make_field_function(children: Vec<ExprNode>, ret: TypeName) -> ExprNode {
     ExprNode {
         expr_type: Field as i32,
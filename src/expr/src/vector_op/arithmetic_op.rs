@@ -18,10 +18,17 @@ use std::fmt::Debug;
 use std::ops::Sub;
 
 use chrono::{Duration, NaiveDateTime};
-use num_traits::{CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Signed, Zero};
+use num_traits::{
+    Bounded, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Float, NumCast, PrimInt,
+    Signed, ToPrimitive, Zero,
+};
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, IntervalArray, NaiveDateTimeArray, PrimitiveArray,
+    PrimitiveArrayItemType,
+};
 use risingwave_common::types::{
-    CheckedAdd, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper,
-    OrderedF64,
+    CheckedAdd, DataType, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
+    NaiveTimeWrapper, OrderedF32, OrderedF64,
 };
 
 use crate::{ExprError, Result};
@@ -80,6 +87,23 @@ where
     })
 }
 
+/// Same as [`general_div`], except that an overflowing quotient saturates instead of erroring.
+/// For integers the only overflowing case is `MIN / -1`, which yields `MAX`. Division by zero
+/// still errors.
+#[inline(always)]
+pub fn general_div_saturating<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: CheckedDiv + Zero + Bounded,
+{
+    general_atm(l, r, |a, b| match a.checked_div(&b) {
+        Some(v) => Ok(v),
+        None if b.is_zero() => Err(ExprError::DivisionByZero),
+        None => Ok(T3::max_value()),
+    })
+}
+
 #[inline(always)]
 pub fn general_mod<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
 where
@@ -301,13 +325,420 @@ where
     r.mul_float(l).ok_or(ExprError::NumericOutOfRange)
 }
 
+#[inline(always)]
+pub fn pow_f64(l: OrderedF64, r: OrderedF64) -> Result<OrderedF64> {
+    if l.0 == 0.0 && r.0 < 0.0 {
+        return Err(ExprError::InvalidParam {
+            name: "exponent",
+            reason: "zero raised to a negative power is undefined".into(),
+        });
+    }
+    if l.0 < 0.0 && r.0.fract() != 0.0 {
+        return Err(ExprError::InvalidParam {
+            name: "exponent",
+            reason: "a negative number raised to a non-integer power yields a complex result"
+                .into(),
+        });
+    }
+    let res = l.0.powf(r.0);
+    if res.is_infinite() && l.0.is_finite() && r.0.is_finite() {
+        return Err(ExprError::NumericOutOfRange);
+    }
+    Ok(res.into())
+}
+
+#[inline(always)]
+pub fn sqrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if input.0 < 0.0 {
+        return Err(ExprError::InvalidParam {
+            name: "input",
+            reason: "cannot take square root of a negative number".into(),
+        });
+    }
+    Ok(input.0.sqrt().into())
+}
+
+/// Errors if a float result is infinite or not a number while the inputs are finite, which means
+/// the result is out of range.
+#[inline(always)]
+fn check_float_result<T: Float>(res: T, inputs_finite: bool) -> Result<T> {
+    if inputs_finite && !res.is_finite() {
+        Err(ExprError::NumericOutOfRange)
+    } else {
+        Ok(res)
+    }
+}
+
+/// Raises each value in `a` to the power at the same position in `b`. A `NULL` on either side
+/// yields `NULL`.
+pub fn vector_pow_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_binary_primitive_float(a, b, |l, r| Ok(l.powf(r)))
+}
+
+/// Computes `e` raised to each value in `a`. A `NULL` yields `NULL`.
+pub fn vector_exp_primitive_float<T>(a: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    let mut builder = <PrimitiveArray<T> as Array>::Builder::new(a.len());
+    for v in a.iter() {
+        match v {
+            Some(v) => builder.append(Some(check_float_result(v.exp(), v.is_finite())?)),
+            None => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Applies `f` to the values at the same position in `a` and `b`. A `NULL` on either side yields
+/// `NULL` without calling `f`.
+pub(crate) fn vector_binary_primitive<T1, T2, F>(
+    a: &PrimitiveArray<T1>,
+    b: &PrimitiveArray<T2>,
+    f: F,
+) -> Result<PrimitiveArray<T1>>
+where
+    T1: PrimitiveArrayItemType,
+    T2: PrimitiveArrayItemType,
+    F: Fn(T1, T2) -> Result<T1>,
+{
+    if a.len() != b.len() {
+        return Err(ExprError::InvalidParam {
+            name: "array",
+            reason: format!("length mismatch: {} vs {}", a.len(), b.len()),
+        });
+    }
+    let mut builder = <PrimitiveArray<T1> as Array>::Builder::new(a.len());
+    for (l, r) in a.iter().zip(b.iter()) {
+        match (l, r) {
+            (Some(l), Some(r)) => builder.append(Some(f(l, r)?)),
+            _ => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Adds each integer in `a` to the integer at the same position in `b`. A `NULL` on either side
+/// yields `NULL`. Errors on overflow.
+pub fn vector_add_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_add::<T, T, T>)
+}
+
+/// Subtracts each integer in `b` from the integer at the same position in `a`. A `NULL` on either
+/// side yields `NULL`. Errors on overflow.
+pub fn vector_sub_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_sub::<T, T, T>)
+}
+
+/// Multiplies each integer in `a` by the integer at the same position in `b`. A `NULL` on either
+/// side yields `NULL`. Errors on overflow.
+pub fn vector_mul_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_mul::<T, T, T>)
+}
+
+fn vector_binary_primitive_float<T, F>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+    f: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+    F: Fn(T, T) -> Result<T>,
+{
+    vector_binary_primitive(a, b, |l, r| {
+        check_float_result(f(l, r)?, l.is_finite() && r.is_finite())
+    })
+}
+
+/// Adds each float in `a` to the float at the same position in `b`. A `NULL` on either side yields
+/// `NULL`. Errors if the sum of finite values is not finite.
+pub fn vector_add_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_binary_primitive_float(a, b, |l, r| Ok(l + r))
+}
+
+/// Subtracts each float in `b` from the float at the same position in `a`. A `NULL` on either side
+/// yields `NULL`. Errors if the difference of finite values is not finite.
+pub fn vector_sub_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_binary_primitive_float(a, b, |l, r| Ok(l - r))
+}
+
+/// Multiplies each float in `a` by the float at the same position in `b`. A `NULL` on either side
+/// yields `NULL`. Errors if the product of finite values is not finite.
+pub fn vector_mul_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_binary_primitive_float(a, b, |l, r| Ok(l * r))
+}
+
+/// Divides each integer in `a` by the integer at the same position in `b`. A `NULL` on either
+/// side yields `NULL`. Errors on division by zero and on overflow (`MIN / -1`).
+pub fn vector_div_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_div::<T, T, T>)
+}
+
+/// Same as [`vector_div_primitive_integer`], except that `MIN / -1` saturates to `MAX` instead of
+/// erroring. This is the only quotient that can overflow, so no other value is clamped. Division
+/// by zero still errors.
+pub fn vector_div_primitive_integer_saturating<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_div_saturating::<T, T, T>)
+}
+
+/// Divides each float in `a` by the float at the same position in `b`. A `NULL` on either side
+/// yields `NULL`. Errors on division by zero and if the quotient of finite values is not finite.
+pub fn vector_div_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_binary_primitive_float(a, b, |l, r| {
+        if r.is_zero() {
+            Err(ExprError::DivisionByZero)
+        } else {
+            Ok(l / r)
+        }
+    })
+}
+
+/// Same as [`vector_div_primitive_float`], except that the quotient follows IEEE 754 instead of
+/// erroring when it is not finite, e.g. `1.0 / 0.0` is `Infinity` and `0.0 / 0.0` is `NaN`.
+pub fn vector_div_primitive_float_ieee<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_binary_primitive(a, b, |l, r| Ok(l / r))
+}
+
+/// Returns the type that dividing a `left` by a `right` yields, which is the wider of the two in
+/// `smallint < int < bigint < real < double precision`, following the signatures of `/` in the
+/// frontend. Other types are not supported by [`vector_div_dispatch`].
+pub fn div_return_type(left: &DataType, right: &DataType) -> Result<DataType> {
+    let rank = |t: &DataType| match t {
+        DataType::Int16 => Ok(0),
+        DataType::Int32 => Ok(1),
+        DataType::Int64 => Ok(2),
+        DataType::Float32 => Ok(3),
+        DataType::Float64 => Ok(4),
+        _ => Err(ExprError::UnsupportedFunction(format!(
+            "vector division over {:?}",
+            t
+        ))),
+    };
+    Ok(if rank(left)? >= rank(right)? {
+        left.clone()
+    } else {
+        right.clone()
+    })
+}
+
+/// Divides `a` by `b` after converting both to `return_type`, which is usually obtained from
+/// [`div_return_type`]. Integers are divided by [`vector_div_primitive_integer`] and floats by
+/// [`vector_div_primitive_float`].
+pub fn vector_div_dispatch(
+    a: &ArrayImpl,
+    b: &ArrayImpl,
+    return_type: &DataType,
+) -> Result<ArrayImpl> {
+    Ok(match return_type {
+        DataType::Int16 => {
+            vector_div_primitive_integer::<i16>(&to_primitive(a)?, &to_primitive(b)?)?.into()
+        }
+        DataType::Int32 => {
+            vector_div_primitive_integer::<i32>(&to_primitive(a)?, &to_primitive(b)?)?.into()
+        }
+        DataType::Int64 => {
+            vector_div_primitive_integer::<i64>(&to_primitive(a)?, &to_primitive(b)?)?.into()
+        }
+        DataType::Float32 => {
+            vector_div_primitive_float::<OrderedF32>(&to_primitive(a)?, &to_primitive(b)?)?.into()
+        }
+        DataType::Float64 => {
+            vector_div_primitive_float::<OrderedF64>(&to_primitive(a)?, &to_primitive(b)?)?.into()
+        }
+        _ => {
+            return Err(ExprError::UnsupportedFunction(format!(
+                "vector division over {:?}",
+                return_type
+            )))
+        }
+    })
+}
+
+/// Converts an integer or float array to `PrimitiveArray<T>`. Errors if a value is out of the
+/// range of `T`.
+fn to_primitive<T>(a: &ArrayImpl) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + NumCast,
+{
+    fn convert<S, T>(a: &PrimitiveArray<S>) -> Result<PrimitiveArray<T>>
+    where
+        S: PrimitiveArrayItemType + ToPrimitive,
+        T: PrimitiveArrayItemType + NumCast,
+    {
+        let mut builder = <PrimitiveArray<T> as Array>::Builder::new(a.len());
+        for v in a.iter() {
+            let v = v
+                .map(|v| <T as NumCast>::from(v).ok_or(ExprError::NumericOutOfRange))
+                .transpose()?;
+            builder.append(v);
+        }
+        Ok(builder.finish())
+    }
+
+    match a {
+        ArrayImpl::Int16(a) => convert(a),
+        ArrayImpl::Int32(a) => convert(a),
+        ArrayImpl::Int64(a) => convert(a),
+        ArrayImpl::Float32(a) => convert(a),
+        ArrayImpl::Float64(a) => convert(a),
+        _ => Err(ExprError::UnsupportedFunction(format!(
+            "vector division over {}",
+            a.get_ident()
+        ))),
+    }
+}
+
+/// Computes the absolute value of each integer in `a`. A `NULL` yields `NULL`. Errors if a value
+/// is the minimum of its type, whose absolute value is out of range.
+pub fn vector_abs_primitive_integer<T>(a: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Signed + CheckedNeg,
+{
+    let mut builder = <PrimitiveArray<T> as Array>::Builder::new(a.len());
+    for v in a.iter() {
+        match v {
+            Some(v) => builder.append(Some(general_abs(v)?)),
+            None => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+pub fn vector_abs_i16(a: &PrimitiveArray<i16>) -> Result<PrimitiveArray<i16>> {
+    vector_abs_primitive_integer(a)
+}
+
+pub fn vector_abs_i32(a: &PrimitiveArray<i32>) -> Result<PrimitiveArray<i32>> {
+    vector_abs_primitive_integer(a)
+}
+
+pub fn vector_abs_i64(a: &PrimitiveArray<i64>) -> Result<PrimitiveArray<i64>> {
+    vector_abs_primitive_integer(a)
+}
+
+/// Computes the absolute value of each float in `a`, which never overflows. A `NULL` yields
+/// `NULL`.
+pub fn vector_abs_primitive_float<T>(a: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    let mut builder = <PrimitiveArray<T> as Array>::Builder::new(a.len());
+    for v in a.iter() {
+        builder.append(v.map(Float::abs));
+    }
+    Ok(builder.finish())
+}
+
+/// Adds each interval in `i` to the timestamp at the same position in `a`. Months are added on
+/// the calendar first, clamping the day to the end of the resulting month (e.g. Jan 31 + 1 month
+/// is Feb 28 or 29), followed by the days and the sub-day part. A `NULL` on either side yields
+/// `NULL`. Errors if a result is out of the range of timestamps.
+pub fn vector_timestamp_add_interval(
+    a: &NaiveDateTimeArray,
+    i: &IntervalArray,
+) -> Result<NaiveDateTimeArray> {
+    vector_binary_primitive(a, i, timestamp_interval_add::<(), (), ()>)
+}
+
+/// Subtracts each interval in `i` from the timestamp at the same position in `a`. This is the
+/// same as [`vector_timestamp_add_interval`] with the intervals negated.
+pub fn vector_timestamp_sub_interval(
+    a: &NaiveDateTimeArray,
+    i: &IntervalArray,
+) -> Result<NaiveDateTimeArray> {
+    vector_binary_primitive(a, i, timestamp_interval_sub::<(), (), ()>)
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use risingwave_common::types::Decimal;
+    use itertools::Itertools;
+    use risingwave_common::array::{
+        Array, ArrayImpl, F32Array, F64Array, I16Array, I32Array, I64Array, IntervalArray,
+        NaiveDateTimeArray,
+    };
+    use risingwave_common::types::{
+        DataType, Decimal, IntervalUnit, NaiveDateTimeWrapper, OrderedF32, OrderedF64,
+    };
 
-    use crate::vector_op::arithmetic_op::general_add;
+    use crate::vector_op::arithmetic_op::{
+        div_return_type, general_add, pow_f64, sqrt_f64, vector_abs_i16, vector_abs_i32,
+        vector_abs_i64, vector_abs_primitive_float, vector_abs_primitive_integer,
+        vector_add_primitive_float, vector_add_primitive_integer, vector_div_dispatch,
+        vector_div_primitive_float, vector_div_primitive_float_ieee, vector_div_primitive_integer,
+        vector_div_primitive_integer_saturating, vector_exp_primitive_float,
+        vector_mul_primitive_float, vector_mul_primitive_integer, vector_pow_primitive_float,
+        vector_sub_primitive_float, vector_sub_primitive_integer, vector_timestamp_add_interval,
+        vector_timestamp_sub_interval,
+    };
+    use crate::ExprError;
 
     #[test]
     fn test() {
@@ -316,4 +747,332 @@ mod tests {
             Decimal::from_str("2").unwrap()
         );
     }
+
+    #[test]
+    fn test_pow_sqrt() {
+        let f = OrderedF64::from;
+        assert_eq!(pow_f64(f(2.0), f(10.0)).unwrap(), f(1024.0));
+        assert_eq!(pow_f64(f(-2.0), f(3.0)).unwrap(), f(-8.0));
+        assert!(pow_f64(f(0.0), f(-1.0)).is_err());
+        assert!(pow_f64(f(-2.0), f(0.5)).is_err());
+        assert!(pow_f64(f(10.0), f(400.0)).is_err());
+
+        assert_eq!(sqrt_f64(f(4.0)).unwrap(), f(2.0));
+        assert!(sqrt_f64(f(-1.0)).is_err());
+    }
+
+    #[test]
+    fn test_vector_pow_exp() {
+        let a = F64Array::from_slice(&[Some(2.0.into()), None, Some(3.0.into())]);
+        let b = F64Array::from_slice(&[Some(10.0.into()), Some(1.0.into()), None]);
+        let c = vector_pow_primitive_float(&a, &b).unwrap();
+        assert_eq!(
+            c.iter().collect_vec(),
+            vec![Some(OrderedF64::from(1024.0)), None, None]
+        );
+
+        let a = F64Array::from_slice(&[Some(0.0.into()), None]);
+        let c = vector_exp_primitive_float(&a).unwrap();
+        assert_eq!(
+            c.iter().collect_vec(),
+            vec![Some(OrderedF64::from(1.0)), None]
+        );
+    }
+
+    #[test]
+    fn test_vector_pow_exp_overflow() {
+        let a = F64Array::from_slice(&[Some(10.0.into())]);
+        let b = F64Array::from_slice(&[Some(400.0.into())]);
+        assert!(vector_pow_primitive_float(&a, &b).is_err());
+
+        let a = F64Array::from_slice(&[Some(1000.0.into())]);
+        assert!(vector_exp_primitive_float(&a).is_err());
+
+        // Infinite inputs are not overflow.
+        let a = F64Array::from_slice(&[Some(f64::INFINITY.into())]);
+        let c = vector_exp_primitive_float(&a).unwrap();
+        assert_eq!(c.value_at(0), Some(OrderedF64::from(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_vector_abs() {
+        let a = I64Array::from_slice(&[Some(3), Some(-5), None, Some(i64::MAX)]);
+        assert_eq!(
+            vector_abs_i64(&a).unwrap().iter().collect_vec(),
+            vec![Some(3), Some(5), None, Some(i64::MAX)]
+        );
+        let a = I32Array::from_slice(&[Some(-7), None]);
+        assert_eq!(
+            vector_abs_i32(&a).unwrap().iter().collect_vec(),
+            vec![Some(7), None]
+        );
+        let a = I16Array::from_slice(&[Some(0), Some(-1)]);
+        assert_eq!(
+            vector_abs_i16(&a).unwrap().iter().collect_vec(),
+            vec![Some(0), Some(1)]
+        );
+
+        assert!(vector_abs_i64(&I64Array::from_slice(&[Some(i64::MIN)])).is_err());
+        assert!(vector_abs_i32(&I32Array::from_slice(&[Some(i32::MIN)])).is_err());
+        assert!(vector_abs_i16(&I16Array::from_slice(&[Some(i16::MIN)])).is_err());
+        assert!(matches!(
+            vector_abs_primitive_integer(&I32Array::from_slice(&[Some(-1), Some(i32::MIN)])),
+            Err(ExprError::NumericOutOfRange)
+        ));
+
+        let a = F64Array::from_slice(&[
+            Some((-1.5).into()),
+            None,
+            Some(f64::MIN.into()),
+            Some(f64::NEG_INFINITY.into()),
+        ]);
+        assert_eq!(
+            vector_abs_primitive_float(&a).unwrap().iter().collect_vec(),
+            vec![
+                Some(OrderedF64::from(1.5)),
+                None,
+                Some(OrderedF64::from(f64::MAX)),
+                Some(OrderedF64::from(f64::INFINITY)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vector_div_saturating() {
+        let a = I32Array::from_slice(&[Some(i32::MIN), Some(7), None, Some(i32::MIN)]);
+        let b = I32Array::from_slice(&[Some(-1), Some(2), Some(1), Some(1)]);
+        assert!(vector_div_primitive_integer(&a, &b).is_err());
+        assert_eq!(
+            vector_div_primitive_integer_saturating(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(i32::MAX), Some(3), None, Some(i32::MIN)]
+        );
+
+        let a = I32Array::from_slice(&[Some(1)]);
+        let b = I32Array::from_slice(&[Some(0)]);
+        assert!(vector_div_primitive_integer_saturating(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_vector_add_sub_overflow() {
+        let a = I16Array::from_slice(&[Some(i16::MAX)]);
+        let b = I16Array::from_slice(&[Some(1)]);
+        assert!(vector_add_primitive_integer(&a, &b).is_err());
+        let a = I16Array::from_slice(&[Some(i16::MIN)]);
+        assert!(vector_sub_primitive_integer(&a, &b).is_err());
+
+        let a = I32Array::from_slice(&[Some(i32::MAX)]);
+        let b = I32Array::from_slice(&[Some(1)]);
+        assert!(vector_add_primitive_integer(&a, &b).is_err());
+        let a = I32Array::from_slice(&[Some(i32::MIN)]);
+        assert!(vector_sub_primitive_integer(&a, &b).is_err());
+
+        let a = I64Array::from_slice(&[Some(i64::MAX)]);
+        let b = I64Array::from_slice(&[Some(1)]);
+        assert!(vector_add_primitive_integer(&a, &b).is_err());
+        let a = I64Array::from_slice(&[Some(i64::MIN)]);
+        assert!(vector_sub_primitive_integer(&a, &b).is_err());
+
+        let a = F64Array::from_slice(&[Some(f64::MAX.into())]);
+        assert!(vector_add_primitive_float(&a, &a).is_err());
+        let b = F64Array::from_slice(&[Some((-f64::MAX).into())]);
+        assert!(vector_sub_primitive_float(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_vector_add_sub_null() {
+        let a = I32Array::from_slice(&[Some(1), None, Some(3), None]);
+        let b = I32Array::from_slice(&[Some(2), Some(2), None, None]);
+        assert_eq!(
+            vector_add_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(3), None, None, None]
+        );
+        assert_eq!(
+            vector_sub_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(-1), None, None, None]
+        );
+
+        let a = F64Array::from_slice(&[Some(1.5.into()), None]);
+        let b = F64Array::from_slice(&[Some(0.5.into()), Some(1.0.into())]);
+        assert_eq!(
+            vector_add_primitive_float(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(OrderedF64::from(2.0)), None]
+        );
+        assert_eq!(
+            vector_sub_primitive_float(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(OrderedF64::from(1.0)), None]
+        );
+    }
+
+    #[test]
+    fn test_vector_mul() {
+        let a = I32Array::from_slice(&[Some(3), None, Some(-4), Some(i32::MAX)]);
+        let b = I32Array::from_slice(&[Some(5), Some(1), None, Some(1)]);
+        assert_eq!(
+            vector_mul_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(15), None, None, Some(i32::MAX)]
+        );
+
+        let a = I32Array::from_slice(&[Some(i32::MAX)]);
+        let b = I32Array::from_slice(&[Some(2)]);
+        assert!(vector_mul_primitive_integer(&a, &b).is_err());
+        let a = I64Array::from_slice(&[Some(i64::MIN)]);
+        let b = I64Array::from_slice(&[Some(-1)]);
+        assert!(vector_mul_primitive_integer(&a, &b).is_err());
+
+        let a = F64Array::from_slice(&[Some(1.5.into()), None, Some(f64::INFINITY.into())]);
+        let b = F64Array::from_slice(&[Some(2.0.into()), Some(1.0.into()), Some(2.0.into())]);
+        assert_eq!(
+            vector_mul_primitive_float(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![
+                Some(OrderedF64::from(3.0)),
+                None,
+                Some(OrderedF64::from(f64::INFINITY))
+            ]
+        );
+
+        let a = F64Array::from_slice(&[Some(f64::MAX.into())]);
+        let b = F64Array::from_slice(&[Some(2.0.into())]);
+        assert!(vector_mul_primitive_float(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_vector_div_float_ieee() {
+        let a = F64Array::from_slice(&[
+            Some(1.0.into()),
+            Some(0.0.into()),
+            Some((-1.0).into()),
+            None,
+        ]);
+        let b = F64Array::from_slice(&[
+            Some(0.0.into()),
+            Some(0.0.into()),
+            Some(0.0.into()),
+            Some(1.0.into()),
+        ]);
+        let c = vector_div_primitive_float_ieee(&a, &b).unwrap();
+        assert_eq!(c.value_at(0), Some(OrderedF64::from(f64::INFINITY)));
+        assert!(c.value_at(1).unwrap().is_nan());
+        assert_eq!(c.value_at(2), Some(OrderedF64::from(f64::NEG_INFINITY)));
+        assert_eq!(c.value_at(3), None);
+
+        for i in 0..3 {
+            let a = F64Array::from_slice(&[a.value_at(i)]);
+            let b = F64Array::from_slice(&[b.value_at(i)]);
+            assert!(vector_div_primitive_float(&a, &b).is_err());
+        }
+        let a = F64Array::from_slice(&[Some(f64::MAX.into())]);
+        let b = F64Array::from_slice(&[Some(0.5.into())]);
+        assert!(vector_div_primitive_float(&a, &b).is_err());
+        assert_eq!(
+            vector_div_primitive_float_ieee(&a, &b).unwrap().value_at(0),
+            Some(OrderedF64::from(f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn test_vector_div_dispatch() {
+        let ints = |values: &[Option<i32>]| -> ArrayImpl { I32Array::from_slice(values).into() };
+
+        // int / bigint
+        let return_type = div_return_type(&DataType::Int32, &DataType::Int64).unwrap();
+        assert_eq!(return_type, DataType::Int64);
+        let b: ArrayImpl = I64Array::from_slice(&[Some(2), Some(2), None]).into();
+        let c = vector_div_dispatch(&ints(&[Some(7), None, Some(1)]), &b, &return_type).unwrap();
+        assert_eq!(c.as_int64().iter().collect_vec(), vec![Some(3), None, None]);
+
+        // int / real
+        let return_type = div_return_type(&DataType::Int32, &DataType::Float32).unwrap();
+        assert_eq!(return_type, DataType::Float32);
+        let b: ArrayImpl = F32Array::from_slice(&[Some(2.0.into())]).into();
+        let c = vector_div_dispatch(&ints(&[Some(7)]), &b, &return_type).unwrap();
+        assert_eq!(
+            c.as_float32().iter().collect_vec(),
+            vec![Some(OrderedF32::from(3.5))]
+        );
+
+        // double precision / real
+        let return_type = div_return_type(&DataType::Float64, &DataType::Float32).unwrap();
+        assert_eq!(return_type, DataType::Float64);
+        let a: ArrayImpl = F64Array::from_slice(&[Some(1.0.into())]).into();
+        let b: ArrayImpl = F32Array::from_slice(&[Some(4.0.into())]).into();
+        let c = vector_div_dispatch(&a, &b, &return_type).unwrap();
+        assert_eq!(
+            c.as_float64().iter().collect_vec(),
+            vec![Some(OrderedF64::from(0.25))]
+        );
+
+        let b: ArrayImpl = F32Array::from_slice(&[Some(0.0.into())]).into();
+        assert!(vector_div_dispatch(&a, &b, &return_type).is_err());
+        assert!(div_return_type(&DataType::Int32, &DataType::Decimal).is_err());
+    }
+
+    #[test]
+    fn test_vector_timestamp_interval() {
+        let ts = |s: &str| NaiveDateTimeWrapper::new(s.parse().unwrap());
+        let a = NaiveDateTimeArray::from_slice(&[
+            Some(ts("2022-01-31T10:00:00")),
+            Some(ts("2024-01-31T10:00:00")),
+            Some(ts("2022-03-01T00:00:00")),
+            None,
+            Some(ts("2022-03-01T00:00:00")),
+        ]);
+        let i = IntervalArray::from_slice(&[
+            Some(IntervalUnit::from_month(1)),
+            Some(IntervalUnit::from_month(1)),
+            Some(IntervalUnit::from_millis(1500)),
+            Some(IntervalUnit::from_month(1)),
+            None,
+        ]);
+        assert_eq!(
+            vector_timestamp_add_interval(&a, &i)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![
+                Some(ts("2022-02-28T10:00:00")),
+                Some(ts("2024-02-29T10:00:00")),
+                Some(ts("2022-03-01T00:00:01.500")),
+                None,
+                None,
+            ]
+        );
+        assert_eq!(
+            vector_timestamp_sub_interval(&a, &i)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![
+                Some(ts("2021-12-31T10:00:00")),
+                Some(ts("2023-12-31T10:00:00")),
+                Some(ts("2022-02-28T23:59:58.500")),
+                None,
+                None,
+            ]
+        );
+
+        let a = NaiveDateTimeArray::from_slice(&[Some(ts("2022-01-01T00:00:00"))]);
+        let i = IntervalArray::from_slice(&[Some(IntervalUnit::from_month(i32::MAX))]);
+        assert!(vector_timestamp_add_interval(&a, &i).is_err());
+    }
 }
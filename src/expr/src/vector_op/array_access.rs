@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::ListRef;
+use risingwave_common::array::{
+    Array, ArrayBuilder, I32Array, ListArray, ListRef, PrimitiveArray, PrimitiveArrayItemType,
+};
 use risingwave_common::types::{Scalar, ToOwnedDatum};
 
-use crate::Result;
+use crate::{ExprError, Result};
 
 #[inline(always)]
 pub fn array_access<T: Scalar>(l: Option<ListRef<'_>>, r: Option<i32>) -> Result<Option<T>> {
@@ -33,11 +35,31 @@ pub fn array_access<T: Scalar>(l: Option<ListRef<'_>>, r: Option<i32>) -> Result
     }
 }
 
+/// Extracts the element at the one-based position in `index` from each list in `arrays`. An
+/// out-of-bounds index, or a `NULL` list or index, yields `NULL`.
+pub fn vector_array_access<T>(arrays: &ListArray, index: &I32Array) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Scalar,
+{
+    if arrays.len() != index.len() {
+        return Err(ExprError::InvalidParam {
+            name: "array",
+            reason: format!("length mismatch: {} vs {}", arrays.len(), index.len()),
+        });
+    }
+    let mut builder = <PrimitiveArray<T> as Array>::Builder::new(arrays.len());
+    for (list, idx) in arrays.iter().zip(index.iter()) {
+        builder.append(array_access::<T>(list, idx)?);
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod tests {
 
-    use risingwave_common::array::ListValue;
-    use risingwave_common::types::ScalarImpl;
+    use itertools::Itertools;
+    use risingwave_common::array::{ArrayImpl, ListValue};
+    use risingwave_common::types::{DataType, ScalarImpl};
 
     use super::*;
 
@@ -109,4 +131,25 @@ mod tests {
             ]))
         );
     }
+
+    #[test]
+    fn test_vector_array_access() {
+        let arrays = ListArray::from_slices(
+            &[true, true, false, true],
+            vec![
+                Some(ArrayImpl::from(I32Array::from_slice(&[Some(1), Some(2)]))),
+                Some(ArrayImpl::from(I32Array::from_slice(&[Some(3), None]))),
+                None,
+                Some(ArrayImpl::from(I32Array::from_slice(&[Some(4)]))),
+            ],
+            DataType::Int32,
+        );
+        let index = I32Array::from_slice(&[Some(2), Some(2), Some(1), Some(2)]);
+        let res = vector_array_access::<i32>(&arrays, &index).unwrap();
+        assert_eq!(res.iter().collect_vec(), vec![Some(2), None, None, None]);
+
+        let index = I32Array::from_slice(&[Some(1), None, Some(1), Some(0)]);
+        let res = vector_array_access::<i32>(&arrays, &index).unwrap();
+        assert_eq!(res.iter().collect_vec(), vec![Some(1), None, None, None]);
+    }
 }
@@ -0,0 +1,86 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::ListRef;
+use risingwave_common::types::ScalarRefImpl;
+
+use crate::Result;
+
+/// Returns the length of the dimension `dim` of `list`. Like in `PostgreSQL`, an empty array or a
+/// dimension beyond the array's yields `NULL`. Arrays are expected to be rectangular, so an inner
+/// dimension is measured by its first element.
+#[inline(always)]
+pub fn array_length(list: Option<ListRef<'_>>, dim: Option<i32>) -> Result<Option<i32>> {
+    let (Some(mut list), Some(dim)) = (list, dim) else {
+        return Ok(None);
+    };
+    if dim < 1 {
+        return Ok(None);
+    }
+    for _ in 1..dim {
+        match list.values_ref().first() {
+            Some(Some(ScalarRefImpl::List(inner))) => list = *inner,
+            _ => return Ok(None),
+        }
+    }
+    let len = list.values_ref().len();
+    Ok((len > 0).then_some(len as i32))
+}
+
+/// Returns the total number of elements of `list` over all its dimensions, e.g. 4 for
+/// `array[array[1, 2], array[3, 4]]`.
+#[inline(always)]
+pub fn cardinality(list: ListRef<'_>) -> Result<i32> {
+    Ok(list.flatten().len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::ListValue;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    fn int_list(values: &[i32]) -> ListValue {
+        ListValue::new(values.iter().map(|v| Some(ScalarImpl::Int32(*v))).collect())
+    }
+
+    #[test]
+    fn test_array_length() {
+        let v = int_list(&[1, 2, 3]);
+        let list = ListRef::ValueRef { val: &v };
+        assert_eq!(array_length(Some(list), Some(1)).unwrap(), Some(3));
+        assert_eq!(array_length(Some(list), Some(2)).unwrap(), None);
+        assert_eq!(array_length(Some(list), Some(0)).unwrap(), None);
+        assert_eq!(array_length(Some(list), None).unwrap(), None);
+        assert_eq!(array_length(None, Some(1)).unwrap(), None);
+        assert_eq!(cardinality(list).unwrap(), 3);
+
+        let v = ListValue::new(vec![
+            Some(ScalarImpl::List(int_list(&[1, 2]))),
+            Some(ScalarImpl::List(int_list(&[3, 4]))),
+            Some(ScalarImpl::List(int_list(&[5, 6]))),
+        ]);
+        let list = ListRef::ValueRef { val: &v };
+        assert_eq!(array_length(Some(list), Some(1)).unwrap(), Some(3));
+        assert_eq!(array_length(Some(list), Some(2)).unwrap(), Some(2));
+        assert_eq!(array_length(Some(list), Some(3)).unwrap(), None);
+        assert_eq!(cardinality(list).unwrap(), 6);
+
+        let v = int_list(&[]);
+        let list = ListRef::ValueRef { val: &v };
+        assert_eq!(array_length(Some(list), Some(1)).unwrap(), None);
+        assert_eq!(cardinality(list).unwrap(), 0);
+    }
+}
@@ -12,15 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Result;
+use risingwave_common::array::{BytesGuard, BytesWriter};
+
+use crate::{ExprError, Result};
 
 #[inline(always)]
 pub fn ascii(s: &str) -> Result<i32> {
     Ok(s.as_bytes().first().map(|x| *x as i32).unwrap_or(0))
 }
 
+/// Returns the character with the given Unicode code point. Like `PostgreSQL`, the NUL character
+/// is not allowed.
+#[inline(always)]
+pub fn chr(code: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let c = u32::try_from(code)
+        .ok()
+        .filter(|code| *code != 0)
+        .and_then(char::from_u32)
+        .ok_or_else(|| ExprError::InvalidParam {
+            name: "code",
+            reason: format!("{} is not a valid character", code),
+        })?;
+    writer
+        .write_ref(c.encode_utf8(&mut [0; 4]))
+        .map_err(Into::into)
+}
+
 #[cfg(test)]
 mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
     use super::*;
 
     #[test]
@@ -30,4 +51,18 @@ mod tests {
             assert_eq!(ascii(s).unwrap(), expected)
         }
     }
+
+    #[test]
+    fn test_chr() {
+        let cases = [(65, "A"), (104, "h"), (20320, "你"), (128519, "😇")];
+        for (code, expected) in cases {
+            let writer = Utf8ArrayBuilder::new(1).writer();
+            let array = chr(code, writer).unwrap().into_inner().finish();
+            assert_eq!(array.value_at(0).unwrap(), expected);
+        }
+        for code in [0, -1, 0xD800, 0x110000] {
+            let writer = Utf8ArrayBuilder::new(1).writer();
+            assert!(chr(code, writer).is_err());
+        }
+    }
 }
@@ -16,9 +16,10 @@ use std::convert::TryInto;
 use std::fmt::Debug;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use num_traits::{CheckedShl, CheckedShr};
+use num_traits::{CheckedShl, CheckedShr, PrimInt};
+use risingwave_common::array::{I32Array, PrimitiveArray, PrimitiveArrayItemType};
 
-use crate::vector_op::arithmetic_op::general_atm;
+use crate::vector_op::arithmetic_op::{general_atm, vector_binary_primitive};
 use crate::{ExprError, Result};
 
 // Conscious decision for shl and shr is made here to diverge from PostgreSQL.
@@ -96,3 +97,150 @@ where
 pub fn general_bitnot<T1: Not<Output = T1>>(expr: T1) -> Result<T1> {
     Ok(expr.not())
 }
+
+/// Computes the bitwise AND of each integer in `a` and the integer at the same position in `b`.
+/// A `NULL` on either side yields `NULL`.
+pub fn vector_bitand_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_bitand::<T, T, T>)
+}
+
+/// Computes the bitwise OR of each integer in `a` and the integer at the same position in `b`.
+/// A `NULL` on either side yields `NULL`.
+pub fn vector_bitor_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_bitor::<T, T, T>)
+}
+
+/// Computes the bitwise XOR of each integer in `a` and the integer at the same position in `b`.
+/// A `NULL` on either side yields `NULL`.
+pub fn vector_bitxor_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt,
+{
+    vector_binary_primitive(a, b, general_bitxor::<T, T, T>)
+}
+
+/// Shifts each integer in `a` left by the count at the same position in `b`. A `NULL` on either
+/// side yields `NULL`. Like [`general_shl`], a count that is negative or not less than the bit
+/// width of `T` is an error rather than shifting every bit out.
+pub fn vector_shl_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &I32Array,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt + CheckedShl,
+{
+    vector_binary_primitive(a, b, general_shl::<T, i32>)
+}
+
+/// Shifts each integer in `a` right by the count at the same position in `b`, extending the sign.
+/// A `NULL` on either side yields `NULL`. Counts out of range error as in
+/// [`vector_shl_primitive_integer`].
+pub fn vector_shr_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &I32Array,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt + CheckedShr,
+{
+    vector_binary_primitive(a, b, general_shr::<T, i32>)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::{Array, I16Array, I64Array};
+
+    use super::*;
+
+    #[test]
+    fn test_vector_bitwise() {
+        let a = I32Array::from_slice(&[Some(0b0011), None, Some(0b0011), Some(-1)]);
+        let b = I32Array::from_slice(&[Some(0b0101), Some(0b0101), None, Some(0b0101)]);
+        assert_eq!(
+            vector_bitand_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(0b0001), None, None, Some(0b0101)]
+        );
+        assert_eq!(
+            vector_bitor_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(0b0111), None, None, Some(-1)]
+        );
+        assert_eq!(
+            vector_bitxor_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(0b0110), None, None, Some(!0b0101)]
+        );
+    }
+
+    #[test]
+    fn test_vector_shift() {
+        let a = I16Array::from_slice(&[Some(1), None, Some(-8), Some(i16::MIN)]);
+        let b = I32Array::from_slice(&[Some(15), Some(1), None, Some(0)]);
+        assert_eq!(
+            vector_shl_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(i16::MIN), None, None, Some(i16::MIN)]
+        );
+
+        let a = I64Array::from_slice(&[Some(-8), Some(1 << 40), None]);
+        let b = I32Array::from_slice(&[Some(2), Some(40), Some(3)]);
+        assert_eq!(
+            vector_shr_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![Some(-2), Some(1), None]
+        );
+    }
+
+    #[test]
+    fn test_vector_shift_out_of_range() {
+        // A count not less than the bit width or negative is an error.
+        let a = I32Array::from_slice(&[None, Some(1)]);
+        let b = I32Array::from_slice(&[Some(1), Some(32)]);
+        assert!(matches!(
+            vector_shl_primitive_integer(&a, &b),
+            Err(ExprError::NumericOutOfRange)
+        ));
+        let a = I16Array::from_slice(&[Some(1)]);
+        let b = I32Array::from_slice(&[Some(16)]);
+        assert!(vector_shr_primitive_integer(&a, &b).is_err());
+        let b = I32Array::from_slice(&[Some(-1)]);
+        assert!(vector_shl_primitive_integer(&a, &b).is_err());
+
+        // A `NULL` count skips the check.
+        let a = I32Array::from_slice(&[Some(1)]);
+        let b = I32Array::from_slice(&[None]);
+        assert_eq!(
+            vector_shl_primitive_integer(&a, &b)
+                .unwrap()
+                .iter()
+                .collect_vec(),
+            vec![None]
+        );
+    }
+}
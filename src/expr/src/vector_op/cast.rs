@@ -16,8 +16,8 @@ use std::any::type_name;
 use std::str::FromStr;
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
-use num_traits::ToPrimitive;
-use risingwave_common::array::{Array, ListRef, ListValue};
+use num_traits::{ToPrimitive, Zero};
+use risingwave_common::array::{Array, ListRef, ListValue, StructRef, StructValue};
 use risingwave_common::types::{
     DataType, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper,
     OrderedF32, OrderedF64, Scalar, ScalarImpl, ScalarRefImpl,
@@ -40,19 +40,108 @@ const PARSE_ERROR_STR_TO_DATE: &str = "Can't cast string to date (expected forma
 
 #[inline(always)]
 pub fn str_to_date(elem: &str) -> Result<NaiveDateWrapper> {
+    str_to_date_at(elem, None)
+}
+
+/// Like [`str_to_date`], but also resolves the relative inputs `'now'`, `'today'`, `'tomorrow'`
+/// and `'yesterday'` against `now`. See [`is_relative_datetime`].
+pub fn str_to_date_at(elem: &str, now: Option<NaiveDateTimeWrapper>) -> Result<NaiveDateWrapper> {
+    if let Some(datetime) = parse_special_datetime(elem, now) {
+        return Ok(NaiveDateWrapper::new(datetime?.date()));
+    }
     Ok(NaiveDateWrapper::new(parse_naive_date(elem)?))
 }
 
 #[inline(always)]
 pub fn str_to_time(elem: &str) -> Result<NaiveTimeWrapper> {
+    str_to_time_at(elem, None)
+}
+
+/// Like [`str_to_time`], but also resolves `'now'` against `now`. As in `PostgreSQL`, the other
+/// relative inputs are not valid times.
+pub fn str_to_time_at(elem: &str, now: Option<NaiveDateTimeWrapper>) -> Result<NaiveTimeWrapper> {
+    if elem.trim().eq_ignore_ascii_case("now") {
+        let now = now.ok_or(ExprError::Parse(
+            "now, today, tomorrow and yesterday can only be cast from constants",
+        ))?;
+        return Ok(NaiveTimeWrapper::new(now.0.time()));
+    }
     Ok(NaiveTimeWrapper::new(parse_naive_time(elem)?))
 }
 
 #[inline(always)]
 pub fn str_to_timestamp(elem: &str) -> Result<NaiveDateTimeWrapper> {
+    str_to_timestamp_at(elem, None)
+}
+
+/// Like [`str_to_timestamp`], but also resolves the relative inputs against `now`. See
+/// [`is_relative_datetime`].
+pub fn str_to_timestamp_at(
+    elem: &str,
+    now: Option<NaiveDateTimeWrapper>,
+) -> Result<NaiveDateTimeWrapper> {
+    if let Some(datetime) = parse_special_datetime(elem, now) {
+        return Ok(NaiveDateTimeWrapper::new(datetime?));
+    }
     Ok(NaiveDateTimeWrapper::new(parse_naive_datetime(elem)?))
 }
 
+/// Returns whether `s` is one of the special inputs `'now'`, `'today'`, `'tomorrow'` or
+/// `'yesterday'`, whose value depends on the current time.
+///
+/// As in `PostgreSQL`, these are resolved against the statement timestamp when a constant is
+/// bound, so the value is frozen at that point: a materialized view or a stored default built from
+/// `'now'::timestamp` keeps the time of its creation rather than following the clock. Casting such
+/// a string at runtime, e.g. from a column, is rejected.
+pub fn is_relative_datetime(s: &str) -> bool {
+    let s = s.trim();
+    ["now", "today", "tomorrow", "yesterday"]
+        .iter()
+        .any(|special| s.eq_ignore_ascii_case(special))
+}
+
+/// Parses the special date/time inputs accepted by `PostgreSQL`, returning `None` if `s` is not
+/// one of them. `'epoch'` is the Unix epoch, and the relative inputs need `now`, with all but
+/// `'now'` itself truncated to midnight. Infinite values are not representable and are rejected.
+fn parse_special_datetime(
+    s: &str,
+    now: Option<NaiveDateTimeWrapper>,
+) -> Option<Result<NaiveDateTime>> {
+    let s = s.trim();
+    let days = if s.eq_ignore_ascii_case("epoch") {
+        return Some(Ok(NaiveDateTime::from_timestamp(0, 0)));
+    } else if ["infinity", "+infinity", "-infinity"]
+        .iter()
+        .any(|special| s.eq_ignore_ascii_case(special))
+    {
+        return Some(Err(ExprError::Parse(
+            "infinite dates and timestamps are not supported",
+        )));
+    } else if s.eq_ignore_ascii_case("now") {
+        None
+    } else if s.eq_ignore_ascii_case("today") {
+        Some(0)
+    } else if s.eq_ignore_ascii_case("tomorrow") {
+        Some(1)
+    } else if s.eq_ignore_ascii_case("yesterday") {
+        Some(-1)
+    } else {
+        return None;
+    };
+    let now = match now {
+        Some(now) => now.0,
+        None => {
+            return Some(Err(ExprError::Parse(
+                "now, today, tomorrow and yesterday can only be cast from constants",
+            )))
+        }
+    };
+    Some(Ok(match days {
+        Some(days) => (now.date() + chrono::Duration::days(days)).and_hms(0, 0, 0),
+        None => now,
+    }))
+}
+
 #[inline]
 fn parse_naive_datetime(s: &str) -> Result<NaiveDateTime> {
     if let Ok(res) = SpeedDateTime::parse_str(s) {
@@ -100,6 +189,15 @@ fn parse_naive_time(s: &str) -> Result<NaiveTime> {
 
 #[inline(always)]
 pub fn str_to_timestampz(elem: &str) -> Result<i64> {
+    str_to_timestampz_at(elem, None)
+}
+
+/// Like [`str_to_timestampz`], but also resolves the relative inputs against `now`, taken as UTC.
+/// See [`is_relative_datetime`].
+pub fn str_to_timestampz_at(elem: &str, now: Option<NaiveDateTimeWrapper>) -> Result<i64> {
+    if let Some(datetime) = parse_special_datetime(elem, now) {
+        return Ok(datetime?.timestamp_nanos() / 1000);
+    }
     DateTime::parse_from_str(elem, "%Y-%m-%d %H:%M:%S %:z")
         .map(|ret| ret.timestamp_nanos() / 1000)
         .map_err(|_| ExprError::Parse(PARSE_ERROR_STR_TO_TIMESTAMP))
@@ -123,6 +221,29 @@ where
         .map_err(|_| ExprError::Cast(type_name::<str>(), type_name::<T>()))
 }
 
+/// Parses an integer of type `T` from a string. As in `PostgreSQL`, a string with a decimal point
+/// such as `'3.5'` is not a valid integer and is rejected rather than truncated; it has to be cast
+/// to `numeric` first, which then rounds when cast to an integer.
+#[inline(always)]
+fn str_to_int<T: FromStr>(elem: &str, error: &'static str) -> Result<T> {
+    elem.trim().parse().map_err(|_| ExprError::Parse(error))
+}
+
+#[inline(always)]
+pub fn str_to_i16(elem: &str) -> Result<i16> {
+    str_to_int(elem, "invalid input syntax for type smallint")
+}
+
+#[inline(always)]
+pub fn str_to_i32(elem: &str) -> Result<i32> {
+    str_to_int(elem, "invalid input syntax for type integer")
+}
+
+#[inline(always)]
+pub fn str_to_i64(elem: &str) -> Result<i64> {
+    str_to_int(elem, "invalid input syntax for type bigint")
+}
+
 /// Define the cast function to primitive types.
 ///
 /// Due to the orphan rule, some data can't implement `TryFrom` trait for basic type.
@@ -202,6 +323,21 @@ pub fn interval_to_time(elem: IntervalUnit) -> Result<NaiveTimeWrapper> {
     )))
 }
 
+/// Casts an interval to its total number of microseconds, counting a month as 30 days.
+#[inline(always)]
+pub fn interval_to_i64(elem: IntervalUnit) -> Result<i64> {
+    elem.total_ms()
+        .checked_mul(1000)
+        .ok_or(ExprError::NumericOutOfRange)
+}
+
+/// Casts a number of microseconds to an interval. The sub-millisecond part is truncated as an
+/// interval only has millisecond precision.
+#[inline(always)]
+pub fn i64_to_interval(elem: i64) -> Result<IntervalUnit> {
+    Ok(IntervalUnit::from_millis(elem / 1000))
+}
+
 #[inline(always)]
 pub fn general_cast<T1, T2>(elem: T1) -> Result<T2>
 where
@@ -226,12 +362,14 @@ pub fn str_to_bool(input: &str) -> Result<bool> {
     {
         Ok(false)
     } else {
-        Err(ExprError::Parse("Invalid bool"))
+        Err(ExprError::Parse("invalid input syntax for type boolean"))
     }
 }
 
-pub fn int32_to_bool(input: i32) -> Result<bool> {
-    Ok(input != 0)
+/// Casts an integer to boolean, which is true for any nonzero value. Floating-point and numeric
+/// values cannot be cast to boolean, as it is unclear whether a small value should be zero.
+pub fn int_to_bool<T: Zero>(input: T) -> Result<bool> {
+    Ok(!input.is_zero())
 }
 
 pub fn general_to_string<T: std::fmt::Display>(elem: T) -> Result<String> {
@@ -259,9 +397,9 @@ macro_rules! for_all_cast_variants {
             { varchar, interval, str_parse },
             { varchar, timestamp, str_to_timestamp },
             { varchar, timestampz, str_to_timestampz },
-            { varchar, int16, str_parse },
-            { varchar, int32, str_parse },
-            { varchar, int64, str_parse },
+            { varchar, int16, str_to_i16 },
+            { varchar, int32, str_to_i32 },
+            { varchar, int64, str_to_i64 },
             { varchar, float32, str_parse },
             { varchar, float64, str_parse },
             { varchar, decimal, str_parse },
@@ -283,7 +421,9 @@ macro_rules! for_all_cast_variants {
             { list, varchar, |x| general_to_string(x) },
 
             { boolean, int32, general_cast },
-            { int32, boolean, int32_to_bool },
+            { int16, boolean, int_to_bool },
+            { int32, boolean, int_to_bool },
+            { int64, boolean, int_to_bool },
 
             { int16, int32, general_cast },
             { int16, int64, general_cast },
@@ -300,6 +440,7 @@ macro_rules! for_all_cast_variants {
             { int64, float32, to_f32 }, // lossy
             { int64, float64, to_f64 }, // lossy
             { int64, decimal, general_cast },
+            { int64, interval, i64_to_interval },
 
             { float32, float64, general_cast },
             { float32, decimal, general_cast },
@@ -322,7 +463,8 @@ macro_rules! for_all_cast_variants {
             { time, interval, general_cast },
             { timestamp, date, timestamp_to_date },
             { timestamp, time, timestamp_to_time },
-            { interval, time, interval_to_time }
+            { interval, time, interval_to_time },
+            { interval, int64, interval_to_i64 }
         }
     };
 }
@@ -383,7 +525,7 @@ pub fn str_to_list(input: &str, target_elem_type: &DataType) -> Result<ListValue
     // For each &str in the comma separated input a ScalarRefImpl is initialized which in turn
     // is cast into the target DataType. If the target DataType is of type Varchar, then
     // no casting is needed.
-    Ok(ListValue::new(
+    let list = ListValue::new(
         unnest(input)?
             .iter()
             .map(|s| {
@@ -395,7 +537,34 @@ pub fn str_to_list(input: &str, target_elem_type: &DataType) -> Result<ListValue
                     .transpose()
             })
             .try_collect()?,
-    ))
+    );
+    if matches!(target_elem_type, DataType::List { .. }) && list_dims(&list).is_none() {
+        return Err(ExprError::Parse(
+            "Multidimensional arrays must have sub-arrays with matching dimensions",
+        ));
+    }
+    Ok(list)
+}
+
+/// Returns the length of each dimension of a multidimensional list, or `None` if the sub-lists
+/// at some level do not all have the same dimensions. A `NULL` element counts as a scalar, so it
+/// cannot stand in for a sub-list.
+fn list_dims(list: &ListValue) -> Option<Vec<usize>> {
+    let mut sub_dims = None;
+    for datum in list.values() {
+        let dims = match datum {
+            Some(ScalarImpl::List(sub_list)) => list_dims(sub_list)?,
+            _ => vec![],
+        };
+        match &sub_dims {
+            None => sub_dims = Some(dims),
+            Some(prev) if *prev != dims => return None,
+            Some(_) => {}
+        }
+    }
+    let mut dims = vec![list.values().len()];
+    dims.extend(sub_dims.unwrap_or_default());
+    Some(dims)
 }
 
 /// Cast array with `source_elem_type` into array with `target_elem_type` by casting each element.
@@ -419,6 +588,106 @@ pub fn list_cast(
     ))
 }
 
+/// Formats a struct as a `PostgreSQL` composite literal, e.g. `(1,abc)`. A field is double-quoted
+/// if it is empty or contains any of `"`, `\`, `(`, `)`, `,` or whitespace, in which case `"` and
+/// `\` inside it are doubled. A `NULL` field is written as nothing at all.
+pub fn struct_to_string(input: StructRef<'_>) -> Result<String> {
+    let mut output = String::from("(");
+    for (idx, field) in input.fields_ref().into_iter().enumerate() {
+        if idx > 0 {
+            output.push(',');
+        }
+        let text = match field {
+            Some(scalar_ref) => scalar_ref.to_string(),
+            None => continue,
+        };
+        let need_quote = text.is_empty()
+            || text
+                .chars()
+                .any(|c| matches!(c, '"' | '\\' | '(' | ')' | ',') || c.is_whitespace());
+        if need_quote {
+            output.push('"');
+            for c in text.chars() {
+                if c == '"' || c == '\\' {
+                    output.push(c);
+                }
+                output.push(c);
+            }
+            output.push('"');
+        } else {
+            output.push_str(&text);
+        }
+    }
+    output.push(')');
+    Ok(output)
+}
+
+/// Parses a `PostgreSQL` composite literal, the reverse of [`struct_to_string`]. An empty unquoted
+/// field is parsed as `NULL`, and every other field is cast from its text to the field type.
+pub fn str_to_struct(input: &str, target_field_types: &[DataType]) -> Result<StructValue> {
+    let trimmed = input.trim();
+    let inner = trimmed
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or(ExprError::Parse("Record literal must be parenthesized"))?;
+
+    // Each item is the unescaped text of a field, or `None` if the field is empty and unquoted.
+    let mut items: Vec<Option<String>> = vec![];
+    let mut text = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                text.push('"');
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                quoted = true;
+            }
+            '\\' => {
+                let escaped = chars
+                    .next()
+                    .ok_or(ExprError::Parse("Unexpected end of record literal"))?;
+                text.push(escaped);
+                quoted = true;
+            }
+            ',' if !in_quotes => {
+                items.push((quoted || !text.is_empty()).then(|| std::mem::take(&mut text)));
+                quoted = false;
+            }
+            c => text.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ExprError::Parse(
+            "Unterminated quoted field in record literal",
+        ));
+    }
+    items.push((quoted || !text.is_empty()).then_some(text));
+
+    if items.len() != target_field_types.len() {
+        return Err(ExprError::Parse(
+            "Record literal has a different number of fields than the target type",
+        ));
+    }
+    Ok(StructValue::new(
+        items
+            .into_iter()
+            .zip(target_field_types)
+            .map(|(item, field_type)| {
+                item.map(|s| match field_type {
+                    DataType::Varchar => Ok(ScalarImpl::Utf8(s)),
+                    _ => scalar_cast(ScalarRefImpl::Utf8(&s), &DataType::Varchar, field_type),
+                })
+                .transpose()
+            })
+            .try_collect()?,
+    ))
+}
+
 /// Cast scalar ref with `source_type` into owned scalar with `target_type`. This function forms a
 /// mutual recursion with `list_cast` so that we can cast nested lists (e.g., varchar[][] to
 /// int[][]).
@@ -445,6 +714,13 @@ fn scalar_cast(
                 datatype: target_elem_type,
             },
         ) => str_to_list(source.try_into()?, target_elem_type).map(Scalar::to_scalar_value),
+        (DataType::Struct(_), DataType::Varchar) => {
+            struct_to_string(source.try_into()?).map(Scalar::to_scalar_value)
+        }
+        (DataType::Varchar, DataType::Struct(target_struct_type)) => {
+            str_to_struct(source.try_into()?, &target_struct_type.fields)
+                .map(Scalar::to_scalar_value)
+        }
         (source_type, target_type) => {
             macro_rules! gen_cast_impl {
                 ($( { $input:ident, $cast:ident, $func:expr } ),*) => {
@@ -501,12 +777,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_str_to_bool() {
+        for input in ["t", "TRUE", "tr", "y", "Yes", "on", "1", " true "] {
+            assert!(str_to_bool(input).unwrap(), "{}", input);
+        }
+        for input in ["f", "False", "n", "NO", "off", "OF", "0"] {
+            assert!(!str_to_bool(input).unwrap(), "{}", input);
+        }
+        // `o` is ambiguous between `on` and `off`.
+        for input in ["maybe", "o", "", "truee", "2"] {
+            assert_eq!(
+                str_to_bool(input).unwrap_err().to_string(),
+                "Parse error: invalid input syntax for type boolean"
+            );
+        }
+    }
+
     #[test]
     fn integer_cast_to_bool() {
         use super::*;
-        assert!(int32_to_bool(32).unwrap());
-        assert!(int32_to_bool(-32).unwrap());
-        assert!(!int32_to_bool(0).unwrap());
+        assert!(int_to_bool(32).unwrap());
+        assert!(int_to_bool(-32).unwrap());
+        assert!(!int_to_bool(0).unwrap());
+        assert!(int_to_bool(5i16).unwrap());
+        assert!(!int_to_bool(0i64).unwrap());
+        assert!(int_to_bool(i64::MIN).unwrap());
     }
 
     #[test]
@@ -541,6 +837,50 @@ mod tests {
         assert_eq!(general_to_string(Decimal::NaN).unwrap(), "NaN");
     }
 
+    #[test]
+    fn test_str_parse_numeric() {
+        assert_eq!(str_parse::<OrderedF64>("1.5e3").unwrap(), 1500.0.into());
+        assert_eq!(str_parse::<OrderedF64>("+0.5").unwrap(), 0.5.into());
+        assert_eq!(str_parse::<i32>("-42").unwrap(), -42);
+        assert_eq!(str_parse::<i32>("+42").unwrap(), 42);
+        assert_eq!(
+            str_parse::<Decimal>("1.5E3").unwrap(),
+            Decimal::from_str("1500").unwrap()
+        );
+        assert_eq!(
+            str_parse::<Decimal>("-2.5e-1").unwrap(),
+            Decimal::from_str("-0.25").unwrap()
+        );
+        assert_eq!(
+            str_parse::<Decimal>("+0.5").unwrap(),
+            Decimal::from_str("0.5").unwrap()
+        );
+
+        assert!(str_parse::<OrderedF64>("1.2.3").is_err());
+        assert!(str_parse::<Decimal>("1.2.3").is_err());
+        assert!(str_parse::<i32>("1.5").is_err());
+        assert!(str_parse::<i32>("--1").is_err());
+    }
+
+    #[test]
+    fn test_str_to_int() {
+        assert_eq!(str_to_i16(" 12 ").unwrap(), 12);
+        assert_eq!(str_to_i32("-42").unwrap(), -42);
+        assert_eq!(str_to_i64("+42").unwrap(), 42);
+        assert_eq!(
+            str_to_i32("3.5").unwrap_err().to_string(),
+            "Parse error: invalid input syntax for type integer"
+        );
+        assert_eq!(
+            str_to_i16("3.0").unwrap_err().to_string(),
+            "Parse error: invalid input syntax for type smallint"
+        );
+        assert!(str_to_i64("3.5e0").is_err());
+        // Going through `numeric` rounds instead.
+        assert_eq!(dec_to_i32(str_parse("3.5").unwrap()).unwrap(), 4);
+        assert_eq!(dec_to_i32(str_parse("-3.5").unwrap()).unwrap(), -4);
+    }
+
     #[test]
     fn temporal_cast() {
         assert_eq!(
@@ -561,6 +901,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_special_temporal_cast() {
+        assert_eq!(
+            str_to_timestamp("epoch").unwrap(),
+            str_to_timestamp("1970-01-01 00:00:00").unwrap(),
+        );
+        assert_eq!(
+            str_to_date(" EPOCH").unwrap(),
+            str_to_date("1970-01-01").unwrap()
+        );
+        assert_eq!(str_to_timestampz("epoch").unwrap(), 0);
+        assert!(str_to_date("infinity").is_err());
+        assert!(str_to_timestamp("-infinity").is_err());
+
+        // Relative inputs are only resolved against a statement time.
+        assert!(str_to_timestamp("now").is_err());
+        assert!(is_relative_datetime("Today"));
+        assert!(!is_relative_datetime("epoch"));
+        let now = Some(str_to_timestamp("1999-01-08 04:02:01").unwrap());
+        assert_eq!(str_to_timestamp_at("now", now).unwrap(), now.unwrap());
+        assert_eq!(
+            str_to_timestamp_at("today", now).unwrap(),
+            str_to_timestamp("1999-01-08").unwrap(),
+        );
+        assert_eq!(
+            str_to_date_at("tomorrow", now).unwrap(),
+            str_to_date("1999-01-09").unwrap(),
+        );
+        assert_eq!(
+            str_to_date_at("yesterday", now).unwrap(),
+            str_to_date("1999-01-07").unwrap(),
+        );
+        assert_eq!(
+            str_to_time_at("now", now).unwrap(),
+            str_to_time("04:02:01").unwrap(),
+        );
+        assert!(str_to_time("now").is_err());
+        assert!(str_to_time_at("today", now).is_err());
+    }
+
+    #[test]
+    fn interval_i64() {
+        assert_eq!(
+            interval_to_i64(IntervalUnit::new(1, 2, 3)).unwrap(),
+            (32 * 86_400_000 + 3) * 1000
+        );
+        assert_eq!(
+            interval_to_i64(IntervalUnit::from_millis(-1500)).unwrap(),
+            -1_500_000
+        );
+        assert_eq!(
+            i64_to_interval(90_000_000).unwrap(),
+            IntervalUnit::from_millis(90_000)
+        );
+        // The sub-millisecond part is truncated.
+        assert_eq!(i64_to_interval(1999).unwrap(), IntervalUnit::from_millis(1));
+    }
+
     #[test]
     fn test_unnest() {
         assert_eq!(
@@ -695,4 +1093,80 @@ mod tests {
         assert!(str_to_list("{{1, 2, 3}, {4, 5, 6}", &DataType::Int32).is_err());
         assert!(str_to_list("{{1, 2, 3}, 4, 5, 6}}", &DataType::Int32).is_err());
     }
+
+    #[test]
+    fn test_str_to_multidimensional_list() {
+        let int_array = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let list = |values: Vec<i32>| {
+            ScalarImpl::List(ListValue::new(
+                values
+                    .into_iter()
+                    .map(|v| Some(v.to_scalar_value()))
+                    .collect(),
+            ))
+        };
+        assert_eq!(
+            str_to_list("{{1,2},{3,4}}", &int_array).unwrap(),
+            ListValue::new(vec![Some(list(vec![1, 2])), Some(list(vec![3, 4]))])
+        );
+
+        // Ragged sub-arrays
+        assert!(str_to_list("{{1,2},{3}}", &int_array).is_err());
+        assert!(str_to_list("{{1,2},{}}", &int_array).is_err());
+        assert!(str_to_list(
+            "{{{1},{2}},{{1,2},{3,4}}}",
+            &DataType::List {
+                datatype: Box::new(int_array.clone())
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_struct_to_string() {
+        let value = StructValue::new(vec![
+            Some(ScalarImpl::Int32(1)),
+            Some(ScalarImpl::Utf8("a".into())),
+            None,
+        ]);
+        assert_eq!(struct_to_string(value.as_scalar_ref()).unwrap(), "(1,a,)");
+
+        let value = StructValue::new(vec![
+            Some(ScalarImpl::Utf8("a,b".into())),
+            Some(ScalarImpl::Utf8("say \"hi\"".into())),
+            Some(ScalarImpl::Utf8("".into())),
+        ]);
+        assert_eq!(
+            struct_to_string(value.as_scalar_ref()).unwrap(),
+            r#"("a,b","say ""hi""","")"#
+        );
+    }
+
+    #[test]
+    fn test_str_to_struct() {
+        let fields = [DataType::Int32, DataType::Varchar, DataType::Varchar];
+        let value = StructValue::new(vec![
+            Some(ScalarImpl::Int32(1)),
+            Some(ScalarImpl::Utf8("a,b".into())),
+            None,
+        ]);
+        let text = struct_to_string(value.as_scalar_ref()).unwrap();
+        assert_eq!(text, r#"(1,"a,b",)"#);
+        assert_eq!(str_to_struct(&text, &fields).unwrap(), value);
+        assert_eq!(
+            str_to_struct(r#"(1,"x""y",\))"#, &fields).unwrap(),
+            StructValue::new(vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Utf8("x\"y".into())),
+                Some(ScalarImpl::Utf8(")".into())),
+            ])
+        );
+
+        assert!(str_to_struct("1,a,b", &fields).is_err());
+        assert!(str_to_struct("(1,a)", &fields).is_err());
+        assert!(str_to_struct(r#"(1,"a,b)"#, &fields).is_err());
+        assert!(str_to_struct("(x,a,b)", &fields).is_err());
+    }
 }
@@ -12,9 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::array::{Array, ArrayBuilder, BytesGuard, BytesWriter, Utf8Array};
 
-use crate::Result;
+use crate::{ExprError, Result};
 
 #[inline(always)]
 pub fn concat_op(left: &str, right: &str, dst: BytesWriter) -> Result<BytesGuard> {
@@ -24,9 +24,41 @@ pub fn concat_op(left: &str, right: &str, dst: BytesWriter) -> Result<BytesGuard
     writer.finish().map_err(Into::into)
 }
 
+/// Concatenates the non-NULL strings at each position of `arrays`, like `CONCAT` in `PostgreSQL`.
+/// A row where every argument is NULL yields an empty string, so the output never contains NULL.
+/// The buffer of each row is reserved from the total length of its arguments up front.
+///
+/// All arrays must have the same length, or an error is returned.
+pub fn vector_concat_n_utf8(arrays: &[&Utf8Array]) -> Result<Utf8Array> {
+    let len = arrays.first().map_or(0, |array| array.len());
+    if let Some(array) = arrays.iter().find(|array| array.len() != len) {
+        return Err(ExprError::InvalidParam {
+            name: "arrays",
+            reason: format!("length mismatch: {} vs {}", len, array.len()),
+        });
+    }
+    let mut builder = <Utf8Array as Array>::Builder::new(len);
+    let mut output = String::new();
+    for row in 0..len {
+        output.clear();
+        output.reserve(
+            arrays
+                .iter()
+                .filter_map(|array| array.value_at(row))
+                .map(str::len)
+                .sum(),
+        );
+        for s in arrays.iter().filter_map(|array| array.value_at(row)) {
+            output.push_str(s);
+        }
+        builder.append(Some(output.as_str()));
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod tests {
-    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+    use risingwave_common::array::Utf8ArrayBuilder;
 
     use super::*;
 
@@ -38,4 +70,28 @@ mod tests {
 
         assert_eq!(array.value_at(0).unwrap(), "114514".to_owned())
     }
+
+    #[test]
+    fn test_vector_concat_n_utf8() {
+        let a = Utf8Array::from_slice(&[Some("a"), None, None, Some("")]);
+        let b = Utf8Array::from_slice(&[Some("bc"), Some("x"), None, None]);
+        let c = Utf8Array::from_slice(&[None, Some("yz"), None, None]);
+        let res = vector_concat_n_utf8(&[&a, &b, &c]).unwrap();
+        assert_eq!(
+            res.iter().collect::<Vec<_>>(),
+            vec![Some("abc"), Some("xyz"), Some(""), Some("")]
+        );
+
+        let res = vector_concat_n_utf8(&[&c]).unwrap();
+        assert_eq!(
+            res.iter().collect::<Vec<_>>(),
+            vec![Some(""), Some("yz"), Some(""), Some("")]
+        );
+
+        let res = vector_concat_n_utf8(&[]).unwrap();
+        assert_eq!(res.len(), 0);
+
+        let d = Utf8Array::from_slice(&[Some("a")]);
+        assert!(vector_concat_n_utf8(&[&a, &d]).is_err());
+    }
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_common::array::{Array, BoolArray};
+
 use crate::Result;
 
 #[inline(always)]
@@ -43,9 +45,44 @@ pub fn not(l: Option<bool>) -> Result<Option<bool>> {
     Ok(l.map(|v| !v))
 }
 
+/// Accumulates a chunk of `BOOL_AND` input into `state`. Unlike [`and`], `NULL`s are ignored, so
+/// the state stays `true` until a `false` appears, and stays `NULL` if every value so far is
+/// `NULL`.
+pub fn vector_bool_and(state: Option<bool>, input: &BoolArray) -> Option<bool> {
+    if state == Some(false) {
+        return state;
+    }
+    let mut state = state;
+    for v in input.iter().flatten() {
+        if !v {
+            return Some(false);
+        }
+        state = Some(true);
+    }
+    state
+}
+
+/// Accumulates a chunk of `BOOL_OR` input into `state`. Unlike [`or`], `NULL`s are ignored, so the
+/// state stays `false` until a `true` appears, and stays `NULL` if every value so far is `NULL`.
+pub fn vector_bool_or(state: Option<bool>, input: &BoolArray) -> Option<bool> {
+    if state == Some(true) {
+        return state;
+    }
+    let mut state = state;
+    for v in input.iter().flatten() {
+        if v {
+            return Some(true);
+        }
+        state = Some(false);
+    }
+    state
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::vector_op::conjunction::{and, or};
+    use risingwave_common::array::BoolArray;
+
+    use crate::vector_op::conjunction::{and, or, vector_bool_and, vector_bool_or};
 
     #[test]
     fn test_and() {
@@ -66,4 +103,25 @@ mod tests {
         assert_eq!(None, or(Some(false), None).unwrap());
         assert_eq!(None, or(None, None).unwrap());
     }
+
+    #[test]
+    fn test_vector_bool_and_or() {
+        let all_true = BoolArray::from_slice(&[Some(true), None, Some(true)]);
+        let mixed = BoolArray::from_slice(&[Some(true), Some(false), None]);
+        let all_null = BoolArray::from_slice(&[None, None]);
+
+        assert_eq!(vector_bool_and(None, &all_true), Some(true));
+        assert_eq!(vector_bool_and(None, &mixed), Some(false));
+        assert_eq!(vector_bool_and(None, &all_null), None);
+        assert_eq!(vector_bool_and(Some(false), &all_true), Some(false));
+        assert_eq!(vector_bool_and(Some(true), &all_null), Some(true));
+
+        assert_eq!(vector_bool_or(None, &all_true), Some(true));
+        assert_eq!(vector_bool_or(None, &mixed), Some(true));
+        assert_eq!(vector_bool_or(None, &all_null), None);
+        let all_false = BoolArray::from_slice(&[Some(false), None]);
+        assert_eq!(vector_bool_or(None, &all_false), Some(false));
+        assert_eq!(vector_bool_or(Some(true), &all_false), Some(true));
+        assert_eq!(vector_bool_or(Some(false), &all_null), Some(false));
+    }
 }
@@ -0,0 +1,275 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
+use risingwave_common::array::{
+    Array, ArrayBuilder, NaiveDateTimeArray, NaiveDateTimeArrayBuilder,
+};
+use risingwave_common::types::{IntervalUnit, NaiveDateTimeWrapper};
+
+use crate::{ExprError, Result};
+
+/// The granularity `date_trunc` truncates a timestamp or an interval to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncField {
+    Microseconds,
+    Milliseconds,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// Truncates to the Monday of the ISO week.
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Decade,
+    /// Centuries start from the year 1, e.g. the 21st century starts from 2001.
+    Century,
+    /// Millenniums start from the year 1, e.g. the 3rd millennium starts from 2001.
+    Millennium,
+}
+
+impl FromStr for TruncField {
+    type Err = ExprError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let field = match s.to_lowercase().as_str() {
+            "microseconds" => Self::Microseconds,
+            "milliseconds" => Self::Milliseconds,
+            "second" => Self::Second,
+            "minute" => Self::Minute,
+            "hour" => Self::Hour,
+            "day" => Self::Day,
+            "week" => Self::Week,
+            "month" => Self::Month,
+            "quarter" => Self::Quarter,
+            "year" => Self::Year,
+            "decade" => Self::Decade,
+            "century" => Self::Century,
+            "millennium" => Self::Millennium,
+            _ => {
+                return Err(ExprError::InvalidParam {
+                    name: "field",
+                    reason: format!("timestamp units \"{}\" not recognized", s),
+                })
+            }
+        };
+        Ok(field)
+    }
+}
+
+/// Truncates `ts` to `field`, zeroing all lower-order components.
+pub fn date_trunc(field: TruncField, ts: NaiveDateTimeWrapper) -> NaiveDateTimeWrapper {
+    let dt = ts.0;
+    let date = dt.date();
+    let truncated = match field {
+        TruncField::Microseconds => dt.with_nanosecond(dt.nanosecond() / 1_000 * 1_000).unwrap(),
+        TruncField::Milliseconds => dt
+            .with_nanosecond(dt.nanosecond() / 1_000_000 * 1_000_000)
+            .unwrap(),
+        TruncField::Second => date.and_hms(dt.hour(), dt.minute(), dt.second()),
+        TruncField::Minute => date.and_hms(dt.hour(), dt.minute(), 0),
+        TruncField::Hour => date.and_hms(dt.hour(), 0, 0),
+        TruncField::Day => date.and_hms(0, 0, 0),
+        TruncField::Week => {
+            let days_from_monday = date.weekday().num_days_from_monday() as i64;
+            (date - Duration::days(days_from_monday)).and_hms(0, 0, 0)
+        }
+        TruncField::Month => NaiveDate::from_ymd(date.year(), date.month(), 1).and_hms(0, 0, 0),
+        TruncField::Quarter => {
+            let month = (date.month() - 1) / 3 * 3 + 1;
+            NaiveDate::from_ymd(date.year(), month, 1).and_hms(0, 0, 0)
+        }
+        TruncField::Year => NaiveDate::from_ymd(date.year(), 1, 1).and_hms(0, 0, 0),
+        TruncField::Decade => {
+            NaiveDate::from_ymd(date.year().div_euclid(10) * 10, 1, 1).and_hms(0, 0, 0)
+        }
+        TruncField::Century => {
+            let year = (date.year() - 1).div_euclid(100) * 100 + 1;
+            NaiveDate::from_ymd(year, 1, 1).and_hms(0, 0, 0)
+        }
+        TruncField::Millennium => {
+            let year = (date.year() - 1).div_euclid(1000) * 1000 + 1;
+            NaiveDate::from_ymd(year, 1, 1).and_hms(0, 0, 0)
+        }
+    };
+    NaiveDateTimeWrapper::new(truncated)
+}
+
+/// Truncates `interval` to `field`, zeroing all lower-order components. Truncating to a week is
+/// not supported as months usually have fractional weeks.
+pub fn date_trunc_interval(field: TruncField, interval: IntervalUnit) -> Result<IntervalUnit> {
+    const MS_PER_SECOND: i64 = 1_000;
+    const MS_PER_MINUTE: i64 = 60 * MS_PER_SECOND;
+    const MS_PER_HOUR: i64 = 60 * MS_PER_MINUTE;
+
+    let months = interval.get_months();
+    let days = interval.get_days();
+    let ms = interval.get_ms();
+    let truncated = match field {
+        // Intervals are stored in milliseconds, so there is nothing to truncate.
+        TruncField::Microseconds | TruncField::Milliseconds => interval,
+        TruncField::Second => IntervalUnit::new(months, days, ms / MS_PER_SECOND * MS_PER_SECOND),
+        TruncField::Minute => IntervalUnit::new(months, days, ms / MS_PER_MINUTE * MS_PER_MINUTE),
+        TruncField::Hour => IntervalUnit::new(months, days, ms / MS_PER_HOUR * MS_PER_HOUR),
+        TruncField::Day => IntervalUnit::new(months, days, 0),
+        TruncField::Week => {
+            return Err(ExprError::InvalidParam {
+                name: "field",
+                reason: "interval units \"week\" not supported because months usually have \
+                         fractional weeks"
+                    .to_string(),
+            })
+        }
+        TruncField::Month => IntervalUnit::from_month(months),
+        TruncField::Quarter => IntervalUnit::from_month(months / 3 * 3),
+        TruncField::Year => IntervalUnit::from_month(months / 12 * 12),
+        TruncField::Decade => IntervalUnit::from_month(months / 120 * 120),
+        TruncField::Century => IntervalUnit::from_month(months / 1200 * 1200),
+        TruncField::Millennium => IntervalUnit::from_month(months / 12000 * 12000),
+    };
+    Ok(truncated)
+}
+
+/// `date_trunc(text, timestamp)`, with the field given as text.
+pub fn date_trunc_timestamp_str(
+    field: &str,
+    ts: NaiveDateTimeWrapper,
+) -> Result<NaiveDateTimeWrapper> {
+    Ok(date_trunc(field.parse()?, ts))
+}
+
+/// `date_trunc(text, interval)`, with the field given as text.
+pub fn date_trunc_interval_str(field: &str, interval: IntervalUnit) -> Result<IntervalUnit> {
+    date_trunc_interval(field.parse()?, interval)
+}
+
+/// Truncates each timestamp in `a` to `field`. A `NULL` yields `NULL`.
+pub fn vector_date_trunc(field: TruncField, a: &NaiveDateTimeArray) -> NaiveDateTimeArray {
+    let mut builder = NaiveDateTimeArrayBuilder::new(a.len());
+    for ts in a.iter() {
+        builder.append(ts.map(|ts| date_trunc(field, ts)));
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn ts(s: &str) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap())
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        let t = ts("2022-08-18 13:45:12.345678");
+        assert_eq!(
+            date_trunc(TruncField::Milliseconds, t),
+            ts("2022-08-18 13:45:12.345")
+        );
+        assert_eq!(date_trunc(TruncField::Second, t), ts("2022-08-18 13:45:12"));
+        assert_eq!(date_trunc(TruncField::Minute, t), ts("2022-08-18 13:45:00"));
+        // 2022-08-18 is a Thursday.
+        assert_eq!(date_trunc(TruncField::Week, t), ts("2022-08-15 00:00:00"));
+        assert_eq!(
+            date_trunc(TruncField::Quarter, t),
+            ts("2022-07-01 00:00:00")
+        );
+        assert_eq!(date_trunc(TruncField::Year, t), ts("2022-01-01 00:00:00"));
+        assert_eq!(date_trunc(TruncField::Decade, t), ts("2020-01-01 00:00:00"));
+        assert_eq!(
+            date_trunc(TruncField::Century, t),
+            ts("2001-01-01 00:00:00")
+        );
+        assert_eq!(
+            date_trunc(TruncField::Millennium, ts("2000-12-31 23:59:59")),
+            ts("1001-01-01 00:00:00")
+        );
+
+        assert_eq!("HOUR".parse::<TruncField>().unwrap(), TruncField::Hour);
+        assert!("fortnight".parse::<TruncField>().is_err());
+    }
+
+    #[test]
+    fn test_date_trunc_interval() {
+        // 1 year 5 months 10 days 12:34:56.789
+        let interval = IntervalUnit::new(17, 10, 45_296_789);
+        let cases = [
+            (
+                TruncField::Milliseconds,
+                IntervalUnit::new(17, 10, 45_296_789),
+            ),
+            (TruncField::Second, IntervalUnit::new(17, 10, 45_296_000)),
+            (TruncField::Minute, IntervalUnit::new(17, 10, 45_240_000)),
+            (TruncField::Hour, IntervalUnit::new(17, 10, 43_200_000)),
+            (TruncField::Day, IntervalUnit::new(17, 10, 0)),
+            (TruncField::Month, IntervalUnit::new(17, 0, 0)),
+            (TruncField::Quarter, IntervalUnit::new(15, 0, 0)),
+            (TruncField::Year, IntervalUnit::new(12, 0, 0)),
+            (TruncField::Decade, IntervalUnit::new(0, 0, 0)),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(date_trunc_interval(field, interval).unwrap(), expected);
+        }
+        assert!(date_trunc_interval(TruncField::Week, interval).is_err());
+
+        assert_eq!(
+            date_trunc_interval_str("YEAR", interval).unwrap(),
+            IntervalUnit::new(12, 0, 0)
+        );
+        assert!(date_trunc_interval_str("fortnight", interval).is_err());
+    }
+
+    #[test]
+    fn test_vector_date_trunc() {
+        let a = NaiveDateTimeArray::from_slice(&[
+            Some(ts("2022-08-18 13:45:12")),
+            None,
+            Some(ts("2021-12-31 23:59:59")),
+        ]);
+        assert_eq!(
+            vector_date_trunc(TruncField::Hour, &a).iter().collect_vec(),
+            vec![
+                Some(ts("2022-08-18 13:00:00")),
+                None,
+                Some(ts("2021-12-31 23:00:00"))
+            ]
+        );
+        assert_eq!(
+            vector_date_trunc(TruncField::Day, &a).iter().collect_vec(),
+            vec![
+                Some(ts("2022-08-18 00:00:00")),
+                None,
+                Some(ts("2021-12-31 00:00:00"))
+            ]
+        );
+        assert_eq!(
+            vector_date_trunc(TruncField::Month, &a)
+                .iter()
+                .collect_vec(),
+            vec![
+                Some(ts("2022-08-01 00:00:00")),
+                None,
+                Some(ts("2021-12-01 00:00:00"))
+            ]
+        );
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ExprError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Specifier {
+    /// `%s`: the argument as a simple string. A `NULL` is an empty string.
+    String,
+    /// `%I`: the argument as an SQL identifier, double-quoted if necessary.
+    Identifier,
+    /// `%L`: the argument as an SQL literal. A `NULL` is `NULL` without quotes.
+    Literal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Specifier(Specifier),
+}
+
+/// A parsed format string of `format(formatstr, args...)`.
+///
+/// Like in `PostgreSQL`, `%s`, `%I` and `%L` each consume the next argument in order and `%%`
+/// is a literal `%`. Positions and widths are not supported yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Formatter {
+    segments: Vec<Segment>,
+}
+
+impl Formatter {
+    pub fn parse(format: &str) -> Result<Self> {
+        let mut segments = vec![];
+        let mut literal = String::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let specifier = match chars.next() {
+                Some('%') => {
+                    literal.push('%');
+                    continue;
+                }
+                Some('s') => Specifier::String,
+                Some('I') => Specifier::Identifier,
+                Some('L') => Specifier::Literal,
+                Some(c) => {
+                    return Err(ExprError::InvalidParam {
+                        name: "format",
+                        reason: format!("unrecognized format() type specifier \"{}\"", c),
+                    })
+                }
+                None => {
+                    return Err(ExprError::InvalidParam {
+                        name: "format",
+                        reason: "unterminated format() type specifier".to_string(),
+                    })
+                }
+            };
+            if !literal.is_empty() {
+                segments.push(Segment::Literal(std::mem::take(&mut literal)));
+            }
+            segments.push(Segment::Specifier(specifier));
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    /// The number of arguments consumed by the format string.
+    pub fn arg_count(&self) -> usize {
+        self.segments
+            .iter()
+            .filter(|segment| matches!(segment, Segment::Specifier(_)))
+            .count()
+    }
+
+    /// Formats `args` into the format string. Arguments beyond those consumed by the format
+    /// string are ignored, while too few arguments is an error.
+    pub fn format(&self, args: &[Option<&str>]) -> Result<String> {
+        if self.arg_count() > args.len() {
+            return Err(ExprError::InvalidParam {
+                name: "format",
+                reason: "too few arguments for format()".to_string(),
+            });
+        }
+        let mut args = args.iter().copied();
+        let mut output = String::new();
+        for segment in &self.segments {
+            let specifier = match segment {
+                Segment::Literal(literal) => {
+                    output.push_str(literal);
+                    continue;
+                }
+                Segment::Specifier(specifier) => specifier,
+            };
+            match (specifier, args.next().unwrap()) {
+                (Specifier::String, arg) => output.push_str(arg.unwrap_or_default()),
+                (Specifier::Identifier, Some(arg)) => quote_ident(arg, &mut output),
+                (Specifier::Identifier, None) => {
+                    return Err(ExprError::InvalidParam {
+                        name: "format",
+                        reason: "null values cannot be formatted as an SQL identifier".to_string(),
+                    })
+                }
+                (Specifier::Literal, Some(arg)) => quote_literal(arg, &mut output),
+                (Specifier::Literal, None) => output.push_str("NULL"),
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Appends `ident`, double-quoted unless it is a lowercase identifier that needs no quotes.
+fn quote_ident(ident: &str, output: &mut String) {
+    let is_simple = ident
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_lowercase() || c == '_')
+        && ident
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if is_simple {
+        output.push_str(ident);
+    } else {
+        output.push('"');
+        output.push_str(&ident.replace('"', "\"\""));
+        output.push('"');
+    }
+}
+
+/// Appends `literal` single-quoted, using the escape string syntax if it contains a backslash.
+fn quote_literal(literal: &str, output: &mut String) {
+    if literal.contains('\\') {
+        output.push('E');
+    }
+    output.push('\'');
+    for c in literal.chars() {
+        if c == '\'' || c == '\\' {
+            output.push(c);
+        }
+        output.push(c);
+    }
+    output.push('\'');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(format: &str, args: &[Option<&str>]) -> Result<String> {
+        Formatter::parse(format)?.format(args)
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format("%s has %s", &[Some("alice"), Some("3")]).unwrap(),
+            "alice has 3"
+        );
+        assert_eq!(format("100%%", &[]).unwrap(), "100%");
+        assert_eq!(format("[%s]", &[None]).unwrap(), "[]");
+        assert_eq!(format("%s", &[Some("a"), Some("ignored")]).unwrap(), "a");
+        assert_eq!(
+            format("%I.%I", &[Some("t_1"), Some("My \"Table\"")]).unwrap(),
+            "t_1.\"My \"\"Table\"\"\""
+        );
+        assert_eq!(
+            format("%L, %L, %L", &[Some("it's"), Some("a\\b"), None]).unwrap(),
+            "'it''s', E'a\\\\b', NULL"
+        );
+    }
+
+    #[test]
+    fn test_format_arg_count() {
+        assert_eq!(Formatter::parse("%s, %I%%%L").unwrap().arg_count(), 3);
+        assert_eq!(Formatter::parse("no args").unwrap().arg_count(), 0);
+    }
+
+    #[test]
+    fn test_format_invalid() {
+        assert!(format("%s and %s", &[Some("a")]).is_err());
+        assert!(format("%I", &[None]).is_err());
+        assert!(Formatter::parse("%x").is_err());
+        assert!(Formatter::parse("50%").is_err());
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+
+use num_traits::{CheckedMul, CheckedNeg, CheckedRem, PrimInt};
+use risingwave_common::array::{PrimitiveArray, PrimitiveArrayItemType};
+
+use crate::vector_op::arithmetic_op::{general_atm, vector_binary_primitive};
+use crate::{ExprError, Result};
+
+/// Computes the greatest common divisor with Euclid's algorithm. The result is never negative, and
+/// `gcd(0, 0)` is 0. Errors if the result is not representable, e.g. `gcd(i32::MIN, 0)`.
+#[inline(always)]
+fn gcd<T>(mut l: T, mut r: T) -> Result<T>
+where
+    T: PrimInt + CheckedNeg + CheckedRem,
+{
+    while !r.is_zero() {
+        // `checked_rem` only fails on `MIN % -1`, which is 0.
+        let rem = l.checked_rem(&r).unwrap_or_else(T::zero);
+        l = r;
+        r = rem;
+    }
+    if l < T::zero() {
+        l.checked_neg().ok_or(ExprError::NumericOutOfRange)
+    } else {
+        Ok(l)
+    }
+}
+
+/// Computes the least common multiple. The result is never negative, and is 0 if either input is 0.
+#[inline(always)]
+fn lcm<T>(l: T, r: T) -> Result<T>
+where
+    T: PrimInt + CheckedNeg + CheckedRem + CheckedMul,
+{
+    if l.is_zero() || r.is_zero() {
+        return Ok(T::zero());
+    }
+    let product = (l / gcd(l, r)?)
+        .checked_mul(&r)
+        .ok_or(ExprError::NumericOutOfRange)?;
+    if product < T::zero() {
+        product.checked_neg().ok_or(ExprError::NumericOutOfRange)
+    } else {
+        Ok(product)
+    }
+}
+
+#[inline(always)]
+pub fn general_gcd<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: PrimInt + CheckedNeg + CheckedRem,
+{
+    general_atm(l, r, gcd)
+}
+
+#[inline(always)]
+pub fn general_lcm<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: PrimInt + CheckedNeg + CheckedRem + CheckedMul,
+{
+    general_atm(l, r, lcm)
+}
+
+/// Computes `n!`. Errors if `n` is negative or the result overflows.
+#[inline(always)]
+pub fn factorial(n: i64) -> Result<i64> {
+    if n < 0 {
+        return Err(ExprError::InvalidParam {
+            name: "n",
+            reason: "factorial of a negative number is undefined".into(),
+        });
+    }
+    (2..=n).try_fold(1i64, |acc, i| {
+        acc.checked_mul(i).ok_or(ExprError::NumericOutOfRange)
+    })
+}
+
+/// Computes `GCD(a, b)` for each row. See [`gcd`] for details.
+pub fn vector_gcd_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt + CheckedNeg + CheckedRem,
+{
+    vector_binary_primitive(a, b, gcd)
+}
+
+/// Computes `LCM(a, b)` for each row. See [`lcm`] for details.
+pub fn vector_lcm_primitive_integer<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + PrimInt + CheckedNeg + CheckedRem + CheckedMul,
+{
+    vector_binary_primitive(a, b, lcm)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::{Array, I32Array, I64Array};
+
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(general_gcd::<i32, i32, i32>(12, 18).unwrap(), 6);
+        assert_eq!(general_gcd::<i32, i32, i32>(-12, 18).unwrap(), 6);
+        assert_eq!(general_gcd::<i16, i64, i64>(7, 0).unwrap(), 7);
+        assert_eq!(general_gcd::<i32, i32, i32>(0, 0).unwrap(), 0);
+        assert_eq!(general_gcd::<i32, i32, i32>(i32::MIN, -1).unwrap(), 1);
+        assert!(general_gcd::<i32, i32, i32>(i32::MIN, 0).is_err());
+
+        let a = I32Array::from_slice(&[Some(12), Some(0), None, Some(-9)]);
+        let b = I32Array::from_slice(&[Some(18), Some(0), Some(1), Some(6)]);
+        let c = vector_gcd_primitive_integer(&a, &b).unwrap();
+        assert_eq!(
+            c.iter().collect_vec(),
+            vec![Some(6), Some(0), None, Some(3)]
+        );
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(general_lcm::<i32, i32, i32>(4, 6).unwrap(), 12);
+        assert_eq!(general_lcm::<i32, i32, i32>(-4, 6).unwrap(), 12);
+        assert_eq!(general_lcm::<i32, i32, i32>(0, 6).unwrap(), 0);
+
+        let a = I64Array::from_slice(&[Some(4), None, Some(0)]);
+        let b = I64Array::from_slice(&[Some(6), Some(1), Some(0)]);
+        let c = vector_lcm_primitive_integer(&a, &b).unwrap();
+        assert_eq!(c.iter().collect_vec(), vec![Some(12), None, Some(0)]);
+    }
+
+    #[test]
+    fn test_lcm_overflow() {
+        assert!(general_lcm::<i32, i32, i32>(i32::MAX, i32::MAX - 1).is_err());
+
+        let a = I64Array::from_slice(&[Some(1), Some(i64::MAX)]);
+        let b = I64Array::from_slice(&[Some(2), Some(2)]);
+        assert!(vector_lcm_primitive_integer(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0).unwrap(), 1);
+        assert_eq!(factorial(5).unwrap(), 120);
+        assert_eq!(factorial(20).unwrap(), 2432902008176640000);
+        assert!(factorial(21).is_err());
+        assert!(factorial(-1).is_err());
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let a = I32Array::from_slice(&[Some(1), Some(2)]);
+        let b = I32Array::from_slice(&[Some(1)]);
+        assert!(vector_gcd_primitive_integer(&a, &b).is_err());
+    }
+}
@@ -15,13 +15,17 @@
 pub mod agg;
 pub mod arithmetic_op;
 pub mod array_access;
+pub mod array_length;
 pub mod ascii;
 pub mod bitwise_op;
 pub mod cast;
 pub mod cmp;
 pub mod concat_op;
 pub mod conjunction;
+pub mod date_trunc;
 pub mod extract;
+pub mod format;
+pub mod gcd_lcm;
 pub mod length;
 pub mod like;
 pub mod lower;
@@ -29,14 +33,18 @@ pub mod ltrim;
 pub mod md5;
 pub mod overlay;
 pub mod position;
+pub mod regexp_matches;
 pub mod repeat;
 pub mod replace;
+pub mod reverse;
 pub mod round;
 pub mod rtrim;
 pub mod split_part;
+pub mod string_to_array;
 pub mod substr;
 pub mod to_char;
 pub mod translate;
+pub mod trigonometric;
 pub mod trim;
 pub mod trim_characters;
 pub mod tumble;
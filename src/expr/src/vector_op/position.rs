@@ -25,6 +25,12 @@ pub fn position(str: &str, sub_str: &str) -> Result<i32> {
     }
 }
 
+/// Whether `str` starts with `prefix`. An empty prefix matches any string.
+#[inline(always)]
+pub fn starts_with(str: &str, prefix: &str) -> Result<bool> {
+    Ok(str.starts_with(prefix))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -42,4 +48,19 @@ mod tests {
             assert_eq!(position(str, sub_str).unwrap(), expected)
         }
     }
+
+    #[test]
+    fn test_starts_with() {
+        let cases = [
+            ("hello world", "hello", true),
+            ("hello world", "world", false),
+            ("床前明月光", "床前", true),
+            ("abc", "", true),
+            ("", "a", false),
+        ];
+
+        for (str, prefix, expected) in cases {
+            assert_eq!(starts_with(str, prefix).unwrap(), expected)
+        }
+    }
 }
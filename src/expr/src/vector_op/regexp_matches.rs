@@ -0,0 +1,191 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::{Captures, RegexBuilder};
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayMeta, ListArray, ListArrayBuilder, ListRef, ListValue, Utf8Array,
+};
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::{ExprError, Result};
+
+/// Creates a builder of `pattern` honoring the `i` flag, i.e. case-insensitive matching, and
+/// returns whether the `g` flag is set, i.e. whether all matches rather than only the first one
+/// are wanted. Other flags are rejected.
+pub fn regex_builder_with_flags(pattern: &str, flags: &str) -> Result<(RegexBuilder, bool)> {
+    let mut global = false;
+    let mut builder = RegexBuilder::new(pattern);
+    for flag in flags.chars() {
+        match flag {
+            'g' => global = true,
+            'i' => {
+                builder.case_insensitive(true);
+            }
+            _ => {
+                return Err(ExprError::InvalidParam {
+                    name: "flags",
+                    reason: format!("invalid regular expression option: \"{}\"", flag),
+                })
+            }
+        }
+    }
+    Ok((builder, global))
+}
+
+/// Matches each string in `a` against `pattern`, yielding one array of text per match. The array
+/// holds the captured groups, or the whole match if the pattern has no groups. A group that does
+/// not take part in the match is `NULL`.
+///
+/// Like `regexp_matches` in `PostgreSQL`, this is set-returning, so the matches of each input row
+/// are returned in a separate array, as [`crate::table_function::TableFunction::eval`] does.
+/// Without the `g` flag only the first match of each string is returned, and a string without any
+/// match or a `NULL` yields an empty array. The `i` flag makes the match case-insensitive. The
+/// pattern is compiled once for the whole array.
+pub fn vector_regexp_matches_utf8(
+    a: &Utf8Array,
+    pattern: &str,
+    flags: &str,
+) -> Result<Vec<ListArray>> {
+    let (builder, global) = regex_builder_with_flags(pattern, flags)?;
+    let regex = builder.build()?;
+    let has_groups = regex.captures_len() > 1;
+    let to_list = |captures: Captures<'_>| {
+        ListValue::new(
+            captures
+                .iter()
+                .skip(has_groups as usize)
+                .map(|mat| mat.map(|mat| ScalarImpl::Utf8(mat.as_str().into())))
+                .collect(),
+        )
+    };
+
+    Ok(a.iter()
+        .map(|text| {
+            let lists: Vec<ListValue> = match text {
+                Some(text) if global => regex.captures_iter(text).map(to_list).collect(),
+                Some(text) => regex.captures(text).map(to_list).into_iter().collect(),
+                None => vec![],
+            };
+            let mut output = ListArrayBuilder::with_meta(
+                lists.len(),
+                ArrayMeta::List {
+                    datatype: Box::new(DataType::Varchar),
+                },
+            );
+            for list in &lists {
+                output.append(Some(ListRef::ValueRef { val: list }));
+            }
+            output.finish()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::types::ScalarRef;
+
+    use super::*;
+
+    type Match = Vec<Option<String>>;
+
+    /// Returns the matches of each row.
+    fn matches(a: &Utf8Array, pattern: &str, flags: &str) -> Vec<Vec<Match>> {
+        vector_regexp_matches_utf8(a, pattern, flags)
+            .unwrap()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|list| {
+                        list.unwrap()
+                            .to_owned_scalar()
+                            .values()
+                            .iter()
+                            .map(|v| v.as_ref().map(|v| v.as_utf8().clone()))
+                            .collect_vec()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec()
+    }
+
+    fn strs(values: &[Option<&str>]) -> Match {
+        values.iter().map(|v| v.map(str::to_string)).collect()
+    }
+
+    #[test]
+    fn test_regexp_matches_groups() {
+        let a = Utf8Array::from_slice(&[Some("foobarbequebaz"), None, Some("nothing"), Some("fb")]);
+        assert_eq!(
+            matches(&a, "(bar)(beque)", ""),
+            vec![
+                vec![strs(&[Some("bar"), Some("beque")])],
+                vec![],
+                vec![],
+                vec![]
+            ]
+        );
+        // Without groups the whole match is returned, and a group that does not take part in the
+        // match is `NULL`.
+        assert_eq!(
+            matches(&a, "b[a-z]r", ""),
+            vec![vec![strs(&[Some("bar")])], vec![], vec![], vec![]]
+        );
+        assert_eq!(
+            matches(&a, "f(o+)?(b)", ""),
+            vec![
+                vec![strs(&[Some("oo"), Some("b")])],
+                vec![],
+                vec![],
+                vec![strs(&[None, Some("b")])],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regexp_matches_flags() {
+        let a = Utf8Array::from_slice(&[Some("bar beque Bazil bar"), Some("bat")]);
+        assert_eq!(
+            matches(&a, "(ba)([a-z])", ""),
+            vec![
+                vec![strs(&[Some("ba"), Some("r")])],
+                vec![strs(&[Some("ba"), Some("t")])],
+            ]
+        );
+        assert_eq!(
+            matches(&a, "(ba)([a-z])", "g"),
+            vec![
+                vec![
+                    strs(&[Some("ba"), Some("r")]),
+                    strs(&[Some("ba"), Some("r")]),
+                ],
+                vec![strs(&[Some("ba"), Some("t")])],
+            ]
+        );
+        assert_eq!(
+            matches(&a, "ba([a-z])", "gi"),
+            vec![
+                vec![strs(&[Some("r")]), strs(&[Some("z")]), strs(&[Some("r")])],
+                vec![strs(&[Some("t")])],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regexp_matches_invalid() {
+        let a = Utf8Array::from_slice(&[Some("a"), Some("b")]);
+        assert!(vector_regexp_matches_utf8(&a, "(a", "").is_err());
+        assert!(vector_regexp_matches_utf8(&a, "a", "x").is_err());
+    }
+}
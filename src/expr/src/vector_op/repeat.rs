@@ -12,12 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::array::{
+    Array, ArrayBuilder, BytesGuard, BytesWriter, I32Array, Utf8Array, Utf8ArrayBuilder,
+};
 
-use crate::Result;
+use crate::{ExprError, Result};
+
+/// The default limit on the length of a single string produced by `repeat`, in bytes. It is the
+/// same as the maximum size of a field in `PostgreSQL`.
+pub const DEFAULT_MAX_REPEAT_LEN: usize = 1 << 30;
+
+/// Returns the length of `s` repeated `count` times, or an error if it would exceed `max_len`.
+#[inline(always)]
+fn repeat_len(s: &str, count: i32, max_len: usize) -> Result<usize> {
+    let count = usize::try_from(count).unwrap_or(0);
+    match s.len().checked_mul(count) {
+        Some(len) if len <= max_len => Ok(len),
+        _ => Err(ExprError::InvalidParam {
+            name: "count",
+            reason: format!(
+                "repeating a string of {} bytes {} times exceeds the maximum length {}",
+                s.len(),
+                count,
+                max_len
+            ),
+        }),
+    }
+}
 
 #[inline(always)]
 pub fn repeat(s: &str, count: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    repeat_len(s, count, DEFAULT_MAX_REPEAT_LEN)?;
     let mut writer = writer.begin();
     for _ in 0..count {
         writer.write_ref(s)?;
@@ -25,9 +50,37 @@ pub fn repeat(s: &str, count: i32, writer: BytesWriter) -> Result<BytesGuard> {
     writer.finish().map_err(Into::into)
 }
 
+/// Repeats each string in `a` by the count at the same position in `n`. A count that is zero or
+/// negative yields an empty string, and a `NULL` on either side yields `NULL`. Errors if any output
+/// string would be longer than `max_len` bytes.
+pub fn vector_repeat_utf8(a: &Utf8Array, n: &I32Array, max_len: usize) -> Result<Utf8Array> {
+    if a.len() != n.len() {
+        return Err(ExprError::InvalidParam {
+            name: "array",
+            reason: format!("length mismatch: {} vs {}", a.len(), n.len()),
+        });
+    }
+    let mut builder = Utf8ArrayBuilder::new(a.len());
+    let mut output = String::new();
+    for (s, count) in a.iter().zip(n.iter()) {
+        match (s, count) {
+            (Some(s), Some(count)) => {
+                output.clear();
+                output.reserve(repeat_len(s, count, max_len)?);
+                for _ in 0..count {
+                    output.push_str(s);
+                }
+                builder.append(Some(output.as_str()));
+            }
+            _ => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod tests {
-    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+    use itertools::Itertools;
 
     use super::*;
 
@@ -50,4 +103,34 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_vector_repeat_utf8() -> Result<()> {
+        let a = Utf8Array::from_slice(&[Some("ab"), Some("ab"), Some("ab"), None, Some("")]);
+        let n = I32Array::from_slice(&[Some(3), Some(0), Some(-1), Some(2), None]);
+        let array = vector_repeat_utf8(&a, &n, DEFAULT_MAX_REPEAT_LEN)?;
+        assert_eq!(
+            array.iter().collect_vec(),
+            vec![Some("ababab"), Some(""), Some(""), None, None]
+        );
+
+        let n = I32Array::from_slice(&[Some(1)]);
+        assert!(vector_repeat_utf8(&a, &n, DEFAULT_MAX_REPEAT_LEN).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_repeat_utf8_too_long() {
+        let a = Utf8Array::from_slice(&[Some("abc")]);
+
+        let n = I32Array::from_slice(&[Some(3)]);
+        let array = vector_repeat_utf8(&a, &n, 9).unwrap();
+        assert_eq!(array.value_at(0), Some("abcabcabc"));
+
+        let n = I32Array::from_slice(&[Some(4)]);
+        assert!(vector_repeat_utf8(&a, &n, 9).is_err());
+
+        let n = I32Array::from_slice(&[Some(i32::MAX)]);
+        assert!(vector_repeat_utf8(&a, &n, DEFAULT_MAX_REPEAT_LEN).is_err());
+    }
 }
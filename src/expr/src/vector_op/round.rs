@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use num_traits::{CheckedDiv, CheckedMul, Float};
+use risingwave_common::array::{
+    Array, ArrayBuilder, DecimalArray, PrimitiveArray, PrimitiveArrayItemType,
+};
 use risingwave_common::types::{Decimal, OrderedF64};
 
-use crate::Result;
+use crate::{ExprError, Result};
 
 #[inline(always)]
 pub fn round_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal> {
@@ -57,11 +61,202 @@ pub fn round_f64(input: OrderedF64) -> Result<OrderedF64> {
 pub fn round_decimal(input: Decimal) -> Result<Decimal> {
     Ok(input.round_dp(0))
 }
+
+/// How a value is rounded to a number of digits.
+#[derive(Clone, Copy)]
+enum RoundMode {
+    /// Ties are broken by rounding away from zero.
+    Round,
+    Ceil,
+    Floor,
+    Trunc,
+}
+
+impl RoundMode {
+    fn round_float<T: Float>(self, input: T) -> T {
+        match self {
+            RoundMode::Round => input.round(),
+            RoundMode::Ceil => input.ceil(),
+            RoundMode::Floor => input.floor(),
+            RoundMode::Trunc => input.trunc(),
+        }
+    }
+
+    fn round_decimal(self, input: Decimal, dp: u32) -> Decimal {
+        match self {
+            RoundMode::Round => input.round_dp(dp),
+            RoundMode::Ceil => input.ceil_dp(dp),
+            RoundMode::Floor => input.floor_dp(dp),
+            RoundMode::Trunc => input.trunc_dp(dp),
+        }
+    }
+}
+
+/// Rounds `input` to `digits` decimal places, or to a multiple of `10^-digits` if `digits` is
+/// negative. The float is scaled by a power of ten first, so a value like `2.345` that has no exact
+/// binary representation may round down; use `numeric` for exact results.
+fn round_float_digits<T: Float>(input: T, digits: i32, mode: RoundMode) -> Result<T> {
+    if !input.is_finite() {
+        return Ok(input);
+    }
+    let ten = T::from(10).unwrap();
+    let res = if digits >= 0 {
+        let factor = ten.powi(digits);
+        let scaled = input * factor;
+        if !scaled.is_finite() {
+            // There are no digits to round at this precision.
+            return Ok(input);
+        }
+        mode.round_float(scaled) / factor
+    } else {
+        let factor = ten.powi(digits.saturating_neg());
+        let rounded = mode.round_float(input / factor);
+        if rounded.is_zero() {
+            return Ok(rounded);
+        }
+        rounded * factor
+    };
+    if res.is_finite() {
+        Ok(res)
+    } else {
+        Err(ExprError::NumericOutOfRange)
+    }
+}
+
+/// Rounds `input` to `digits` decimal places, or to a multiple of `10^-digits` if `digits` is
+/// negative, without going through floats.
+fn round_decimal_digits(input: Decimal, digits: i32, mode: RoundMode) -> Result<Decimal> {
+    if !matches!(input, Decimal::Normalized(_)) {
+        return Ok(input);
+    }
+    if digits >= 0 {
+        return Ok(mode.round_decimal(input, digits as u32));
+    }
+    // A decimal has at most 28 digits, so it is smaller than any larger power of ten.
+    const MAX_DIGITS: u32 = 28;
+    let exp = digits.unsigned_abs();
+    if exp > MAX_DIGITS {
+        return match mode {
+            RoundMode::Ceil if input > Decimal::zero() => Err(ExprError::NumericOutOfRange),
+            RoundMode::Floor if input < Decimal::zero() => Err(ExprError::NumericOutOfRange),
+            _ => Ok(Decimal::zero()),
+        };
+    }
+    let factor = Decimal::from_i128_with_scale(10i128.pow(exp), 0);
+    let scaled = input
+        .checked_div(&factor)
+        .ok_or(ExprError::NumericOutOfRange)?;
+    mode.round_decimal(scaled, 0)
+        .checked_mul(&factor)
+        .ok_or(ExprError::NumericOutOfRange)
+}
+
+fn vector_round_float<T>(
+    a: &PrimitiveArray<T>,
+    digits: Option<i32>,
+    mode: RoundMode,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    let mut builder = <PrimitiveArray<T> as Array>::Builder::new(a.len());
+    for v in a.iter() {
+        match v {
+            Some(v) => builder.append(Some(round_float_digits(v, digits.unwrap_or(0), mode)?)),
+            None => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn vector_round_decimal_with_mode(
+    a: &DecimalArray,
+    digits: Option<i32>,
+    mode: RoundMode,
+) -> Result<DecimalArray> {
+    let mut builder = <DecimalArray as Array>::Builder::new(a.len());
+    for v in a.iter() {
+        match v {
+            Some(v) => builder.append(Some(round_decimal_digits(v, digits.unwrap_or(0), mode)?)),
+            None => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Rounds each value in `a` to `digits` decimal places, or to an integer if `digits` is `None`.
+/// Ties are broken by rounding away from zero. A `NULL` yields `NULL`.
+pub fn vector_round_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    digits: Option<i32>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_round_float(a, digits, RoundMode::Round)
+}
+
+/// Like [`vector_round_primitive_float`], but rounds towards positive infinity.
+pub fn vector_ceil_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    digits: Option<i32>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_round_float(a, digits, RoundMode::Ceil)
+}
+
+/// Like [`vector_round_primitive_float`], but rounds towards negative infinity.
+pub fn vector_floor_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    digits: Option<i32>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_round_float(a, digits, RoundMode::Floor)
+}
+
+/// Like [`vector_round_primitive_float`], but rounds towards zero.
+pub fn vector_trunc_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    digits: Option<i32>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    vector_round_float(a, digits, RoundMode::Trunc)
+}
+
+/// Rounds each value in `a` to `digits` decimal places, or to an integer if `digits` is `None`.
+/// Ties are broken by rounding away from zero. Unlike [`vector_round_primitive_float`], this is
+/// exact. A `NULL` yields `NULL`.
+pub fn vector_round_decimal(a: &DecimalArray, digits: Option<i32>) -> Result<DecimalArray> {
+    vector_round_decimal_with_mode(a, digits, RoundMode::Round)
+}
+
+/// Like [`vector_round_decimal`], but rounds towards positive infinity.
+pub fn vector_ceil_decimal(a: &DecimalArray, digits: Option<i32>) -> Result<DecimalArray> {
+    vector_round_decimal_with_mode(a, digits, RoundMode::Ceil)
+}
+
+/// Like [`vector_round_decimal`], but rounds towards negative infinity.
+pub fn vector_floor_decimal(a: &DecimalArray, digits: Option<i32>) -> Result<DecimalArray> {
+    vector_round_decimal_with_mode(a, digits, RoundMode::Floor)
+}
+
+/// Like [`vector_round_decimal`], but rounds towards zero.
+pub fn vector_trunc_decimal(a: &DecimalArray, digits: Option<i32>) -> Result<DecimalArray> {
+    vector_round_decimal_with_mode(a, digits, RoundMode::Trunc)
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use num_traits::FromPrimitive;
+    use risingwave_common::array::{DecimalArray, F64Array};
     use risingwave_common::types::{Decimal, OrderedF64};
 
     use super::ceil_f64;
@@ -115,6 +310,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_vector_round_decimal() {
+        let dec = |s: &str| Some(Decimal::from_str(s).unwrap());
+        let a = DecimalArray::from_slice(&[dec("2.5"), None, dec("-2.5"), dec("2.345")]);
+        let round = |digits| {
+            vector_round_decimal(&a, digits)
+                .unwrap()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let strs = |v: &[Option<&str>]| v.iter().map(|v| v.map(str::to_string)).collect::<Vec<_>>();
+        assert_eq!(round(None), strs(&[Some("3"), None, Some("-3"), Some("2")]));
+        assert_eq!(
+            round(Some(2)),
+            strs(&[Some("2.5"), None, Some("-2.5"), Some("2.35")])
+        );
+
+        let a = DecimalArray::from_slice(&[dec("1234.5678"), dec("-1234.5678")]);
+        let to_strings = |res: DecimalArray| {
+            res.iter()
+                .map(|v| v.unwrap().normalize().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            to_strings(vector_ceil_decimal(&a, Some(2)).unwrap()),
+            ["1234.57", "-1234.56"]
+        );
+        assert_eq!(
+            to_strings(vector_floor_decimal(&a, Some(2)).unwrap()),
+            ["1234.56", "-1234.57"]
+        );
+        assert_eq!(
+            to_strings(vector_trunc_decimal(&a, Some(-2)).unwrap()),
+            ["1200", "-1200"]
+        );
+        assert_eq!(
+            to_strings(vector_round_decimal(&a, Some(-2)).unwrap()),
+            ["1200", "-1200"]
+        );
+        assert_eq!(
+            to_strings(vector_round_decimal(&a, Some(-30)).unwrap()),
+            ["0", "0"]
+        );
+        assert!(vector_ceil_decimal(&a, Some(-30)).is_err());
+    }
+
+    #[test]
+    fn test_vector_round_float() {
+        let a = F64Array::from_slice(&[
+            Some(2.5.into()),
+            None,
+            Some((-2.5).into()),
+            Some(1234.5678.into()),
+        ]);
+        let values = |res: F64Array| res.iter().map(|v| v.map(|v| v.0)).collect::<Vec<_>>();
+        assert_eq!(
+            values(vector_round_primitive_float(&a, None).unwrap()),
+            [Some(3.0), None, Some(-3.0), Some(1235.0)]
+        );
+        assert_eq!(
+            values(vector_trunc_primitive_float(&a, None).unwrap()),
+            [Some(2.0), None, Some(-2.0), Some(1234.0)]
+        );
+        assert_eq!(
+            values(vector_round_primitive_float(&a, Some(-2)).unwrap()),
+            [Some(0.0), None, Some(-0.0), Some(1200.0)]
+        );
+        assert_eq!(
+            values(vector_ceil_primitive_float(&a, Some(1)).unwrap()),
+            [Some(2.5), None, Some(-2.5), Some(1234.6)]
+        );
+        assert_eq!(
+            values(vector_floor_primitive_float(&a, Some(400)).unwrap()),
+            [Some(2.5), None, Some(-2.5), Some(1234.5678)]
+        );
+        let a = F64Array::from_slice(&[Some(f64::MAX.into())]);
+        assert!(vector_ceil_primitive_float(&a, Some(-300)).is_err());
+    }
+
     #[test]
     fn test_round_decimal() {
         assert_eq!(
@@ -16,6 +16,9 @@ use risingwave_common::array::{BytesGuard, BytesWriter};
 
 use crate::{ExprError, Result};
 
+/// Returns the `nth_expr`-th field of `string_expr` split by `delimiter_expr`, counting from 1. A
+/// negative `nth_expr` counts from the end, and a field beyond the last one is an empty string.
+/// Like in `PostgreSQL`, a zero `nth_expr` is an error.
 #[inline(always)]
 pub fn split_part(
     string_expr: &str,
@@ -25,8 +28,8 @@ pub fn split_part(
 ) -> Result<BytesGuard> {
     if nth_expr == 0 {
         return Err(ExprError::InvalidParam {
-            name: "data",
-            reason: "can't be zero".to_string(),
+            name: "n",
+            reason: "field position must not be zero".to_string(),
         });
     };
 
@@ -0,0 +1,112 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{ListRef, ListValue};
+use risingwave_common::types::ScalarImpl;
+
+/// Splits `s` on `delim`. Following `PostgreSQL`, a `NULL` delimiter splits `s` into single
+/// characters, an empty delimiter returns `s` as the only element, and an empty `s` yields an
+/// empty array. Elements equal to `null_string` become `NULL`.
+pub fn string_to_array(s: &str, delim: Option<&str>, null_string: Option<&str>) -> ListValue {
+    let to_datum = |elem: &str| (Some(elem) != null_string).then(|| ScalarImpl::Utf8(elem.into()));
+    if s.is_empty() {
+        return ListValue::new(vec![]);
+    }
+    let values = match delim {
+        None => s
+            .char_indices()
+            .map(|(idx, c)| to_datum(&s[idx..idx + c.len_utf8()]))
+            .collect(),
+        Some("") => vec![to_datum(s)],
+        Some(delim) => s.split(delim).map(to_datum).collect(),
+    };
+    ListValue::new(values)
+}
+
+/// Joins the elements of `list` with `delim`, writing each element in its text form. `NULL`
+/// elements are skipped, unless `null_string` is given, in which case they are written as it.
+pub fn array_to_string(list: ListRef<'_>, delim: &str, null_string: Option<&str>) -> String {
+    let mut output = String::new();
+    let mut first = true;
+    for elem in list.values_ref() {
+        let text = match (elem, null_string) {
+            (Some(scalar_ref), _) => scalar_ref.to_string(),
+            (None, Some(null_string)) => null_string.to_string(),
+            (None, None) => continue,
+        };
+        if !first {
+            output.push_str(delim);
+        }
+        first = false;
+        output.push_str(&text);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn varchar_list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| ScalarImpl::Utf8(v.into())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_string_to_array() {
+        assert_eq!(
+            string_to_array("a,b,,c", Some(","), None),
+            varchar_list(&[Some("a"), Some("b"), Some(""), Some("c")])
+        );
+        assert_eq!(
+            string_to_array("a,NULL,c", Some(","), Some("NULL")),
+            varchar_list(&[Some("a"), None, Some("c")])
+        );
+        assert_eq!(
+            string_to_array("abc", Some(""), None),
+            varchar_list(&[Some("abc")])
+        );
+        assert_eq!(
+            string_to_array("aé", None, None),
+            varchar_list(&[Some("a"), Some("é")])
+        );
+        assert_eq!(string_to_array("", Some(","), None), varchar_list(&[]));
+    }
+
+    #[test]
+    fn test_array_to_string() {
+        let list = ListValue::new(vec![
+            Some(ScalarImpl::Int32(1)),
+            None,
+            Some(ScalarImpl::Int32(3)),
+        ]);
+        let list_ref = ListRef::ValueRef { val: &list };
+        assert_eq!(array_to_string(list_ref, ",", None), "1,3");
+        assert_eq!(array_to_string(list_ref, ", ", Some("*")), "1, *, 3");
+        assert_eq!(
+            array_to_string(
+                ListRef::ValueRef {
+                    val: &varchar_list(&[])
+                },
+                ",",
+                None
+            ),
+            ""
+        );
+    }
+}
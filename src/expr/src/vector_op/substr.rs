@@ -45,6 +45,41 @@ pub fn substr_start_for(
     writer.write_ref(&s[begin..end]).map_err(Into::into)
 }
 
+/// Returns the byte offset of the `n`-th character of `s`, or the length of `s` if it has `n`
+/// characters or fewer.
+#[inline(always)]
+fn char_offset(s: &str, n: usize) -> usize {
+    s.char_indices()
+        .nth(n)
+        .map_or(s.len(), |(offset, _)| offset)
+}
+
+/// Returns the first `n` characters of `s`, or all but the last `|n|` characters if `n` is
+/// negative.
+#[inline(always)]
+pub fn left(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let end = if n >= 0 {
+        char_offset(s, n as usize)
+    } else {
+        let len = s.chars().count();
+        char_offset(s, len.saturating_sub(n.unsigned_abs() as usize))
+    };
+    writer.write_ref(&s[..end]).map_err(Into::into)
+}
+
+/// Returns the last `n` characters of `s`, or all but the first `|n|` characters if `n` is
+/// negative.
+#[inline(always)]
+pub fn right(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let start = if n >= 0 {
+        let len = s.chars().count();
+        char_offset(s, len.saturating_sub(n as usize))
+    } else {
+        char_offset(s, n.unsigned_abs() as usize)
+    };
+    writer.write_ref(&s[start..]).map_err(Into::into)
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
@@ -87,4 +122,34 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_left_right() -> Result<()> {
+        let cases = [
+            ("abcde", 2, "ab", "de"),
+            ("abcde", -2, "abc", "cde"),
+            ("abcde", 0, "", ""),
+            ("abcde", 10, "abcde", "abcde"),
+            ("abcde", -10, "", ""),
+            ("abcde", i32::MIN, "", ""),
+            ("héllo", 2, "hé", "lo"),
+            ("héllo", -4, "h", "o"),
+        ];
+
+        for (s, n, expected_left, expected_right) in cases {
+            for (f, expected) in [
+                (
+                    left as fn(&str, i32, BytesWriter) -> Result<BytesGuard>,
+                    expected_left,
+                ),
+                (right, expected_right),
+            ] {
+                let builder = Utf8ArrayBuilder::new(1);
+                let writer = builder.writer();
+                let array = f(s, n, writer)?.into_inner().finish();
+                assert_eq!(array.value_at(0).unwrap(), expected);
+            }
+        }
+        Ok(())
+    }
 }
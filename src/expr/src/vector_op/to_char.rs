@@ -12,33 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use aho_corasick::AhoCorasickBuilder;
-use risingwave_common::array::{BytesGuard, BytesWriter};
-use risingwave_common::types::NaiveDateTimeWrapper;
+use std::iter::Peekable;
+use std::str::Chars;
 
-use crate::Result;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
+use chrono::format::{parse, Parsed, StrftimeItems};
+use chrono::NaiveDateTime;
+use risingwave_common::array::{
+    Array, ArrayBuilder, BytesGuard, BytesWriter, DecimalArray, Utf8Array, Utf8ArrayBuilder,
+};
+use risingwave_common::types::{Decimal, NaiveDateTimeWrapper, NaiveDateWrapper};
 
-/// Compile the pg pattern to chrono pattern.
-// TODO: Chrono can not fully support the pg format, so consider using other implementations later.
-pub fn compile_pattern_to_chrono(tmpl: &str) -> String {
-    // https://www.postgresql.org/docs/current/functions-formatting.html
-    static PG_PATTERNS: &[&str] = &[
-        "HH24", "hh24", "HH12", "hh12", "HH", "hh", "MI", "mi", "SS", "ss", "YYYY", "yyyy", "YY",
-        "yy", "IYYY", "iyyy", "IY", "iy", "MM", "mm", "DD", "dd",
-    ];
-    // https://docs.rs/chrono/latest/chrono/format/strftime/index.html
-    static CHRONO_PATTERNS: &[&str] = &[
-        "%H", "%H", "%I", "%I", "%I", "%I", "%M", "%M", "%S", "%S", "%Y", "%Y", "%y", "%y", "%G",
-        "%G", "%g", "%g", "%m", "%m", "%d", "%d",
-    ];
-
-    let ac = AhoCorasickBuilder::new()
+use crate::{ExprError, Result};
+
+// https://www.postgresql.org/docs/current/functions-formatting.html
+static PG_PATTERNS: &[&str] = &[
+    "HH24", "hh24", "HH12", "hh12", "HH", "hh", "MI", "mi", "SS", "ss", "YYYY", "yyyy", "YY", "yy",
+    "IYYY", "iyyy", "IY", "iy", "MM", "mm", "DD", "dd",
+];
+// https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+static CHRONO_PATTERNS: &[&str] = &[
+    "%H", "%H", "%I", "%I", "%I", "%I", "%M", "%M", "%S", "%S", "%Y", "%Y", "%y", "%y", "%G", "%G",
+    "%g", "%g", "%m", "%m", "%d", "%d",
+];
+
+fn pg_pattern_matcher() -> AhoCorasick {
+    AhoCorasickBuilder::new()
         .ascii_case_insensitive(false)
         .match_kind(aho_corasick::MatchKind::LeftmostLongest)
-        .build(PG_PATTERNS);
+        .build(PG_PATTERNS)
+}
 
+/// Compile the pg pattern to chrono pattern.
+// TODO: Chrono can not fully support the pg format, so consider using other implementations later.
+pub fn compile_pattern_to_chrono(tmpl: &str) -> String {
     let mut chrono_tmpl = String::new();
-    ac.replace_all_with(tmpl, &mut chrono_tmpl, |mat, _, dst| {
+    pg_pattern_matcher().replace_all_with(tmpl, &mut chrono_tmpl, |mat, _, dst| {
         dst.push_str(CHRONO_PATTERNS[mat.pattern()]);
         true
     });
@@ -46,6 +55,28 @@ pub fn compile_pattern_to_chrono(tmpl: &str) -> String {
     chrono_tmpl
 }
 
+/// Checks that `tmpl` only consists of the supported patterns and separators. Unlike
+/// `PostgreSQL`, letters outside a pattern are rejected rather than copied, so that a typo or an
+/// unsupported pattern such as `Month` is not silently kept in the output.
+pub fn check_pattern(tmpl: &str) -> Result<()> {
+    let mut last_end = 0;
+    let check_literal = |literal: &str| match literal
+        .chars()
+        .find(|c| c.is_ascii_alphabetic() || *c == '%')
+    {
+        Some(c) => Err(ExprError::InvalidParam {
+            name: "format",
+            reason: format!("invalid datetime format pattern \"{}\" in \"{}\"", c, tmpl),
+        }),
+        None => Ok(()),
+    };
+    for mat in pg_pattern_matcher().find_iter(tmpl) {
+        check_literal(&tmpl[last_end..mat.start()])?;
+        last_end = mat.end();
+    }
+    check_literal(&tmpl[last_end..])
+}
+
 pub fn to_char_timestamp(
     data: NaiveDateTimeWrapper,
     tmpl: &str,
@@ -55,3 +86,416 @@ pub fn to_char_timestamp(
     let res = data.0.format(&chrono_tmpl).to_string();
     dst.write_ref(&res).map_err(Into::into)
 }
+
+/// Parses `s` with the pg pattern `tmpl`. As in `PostgreSQL`, fields missing from the pattern take
+/// the start of their range, e.g. the time is midnight if `tmpl` has no hour, and a 12-hour clock
+/// without a meridiem is taken as AM.
+fn parse_with_pattern(s: &str, tmpl: &str) -> Result<NaiveDateTime> {
+    let invalid = || ExprError::InvalidParam {
+        name: "string",
+        reason: format!("invalid value \"{}\" for format \"{}\"", s, tmpl),
+    };
+    let chrono_tmpl = compile_pattern_to_chrono(tmpl);
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, s, StrftimeItems::new(&chrono_tmpl)).map_err(|_| invalid())?;
+    if parsed.isoyear.is_none() {
+        parsed.month.get_or_insert(1);
+        parsed.day.get_or_insert(1);
+    }
+    let date = parsed.to_naive_date().map_err(|_| invalid())?;
+    if parsed.hour_mod_12.is_none() {
+        return Ok(date.and_hms(0, 0, 0));
+    }
+    parsed.hour_div_12.get_or_insert(0);
+    parsed.minute.get_or_insert(0);
+    let time = parsed.to_naive_time().map_err(|_| invalid())?;
+    Ok(date.and_time(time))
+}
+
+/// `to_timestamp(text, text)`, the inverse of `to_char` for timestamps.
+pub fn to_timestamp(s: &str, tmpl: &str) -> Result<NaiveDateTimeWrapper> {
+    parse_with_pattern(s, tmpl).map(NaiveDateTimeWrapper)
+}
+
+/// `to_date(text, text)`. Any time fields in the pattern are parsed but ignored.
+pub fn to_date(s: &str, tmpl: &str) -> Result<NaiveDateWrapper> {
+    parse_with_pattern(s, tmpl).map(|datetime| NaiveDateWrapper(datetime.date()))
+}
+
+/// An element of a numeric `to_char` format mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumericFormatNode {
+    /// `9`, or `0` if `zero` is set, which keeps leading and trailing zeros.
+    Digit {
+        zero: bool,
+    },
+    /// `.` or `D`
+    DecimalPoint,
+    /// `,` or `G`
+    Group,
+    /// `S`, `MI`, `PL` or `SG`
+    Sign(SignFormat),
+    /// `L`
+    Currency,
+    Literal(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignFormat {
+    /// `S` or `SG`: `+` or `-`
+    PlusMinus,
+    /// `MI`: `-` or blank
+    Minus,
+    /// `PL`: `+` or blank
+    Plus,
+}
+
+/// A numeric `to_char` format mask, parsed once so that an invalid mask is reported only once.
+///
+/// The locale-dependent codes `D`, `G` and `L` use the C locale, i.e. `.`, `,` and `$`. Other
+/// letters are rejected rather than copied, since they are likely unsupported codes such as `EEEE`,
+/// `RN` or `V`.
+#[derive(Debug)]
+struct NumericFormat {
+    nodes: Vec<NumericFormatNode>,
+    /// `FM` drops the padding blanks and the trailing zeros of the `9` fractional digits.
+    fill_mode: bool,
+    int_digits: usize,
+    frac_digits: usize,
+    /// Leading zeros are kept from the first integer `0` of the mask on.
+    zero_start: usize,
+    /// Without an explicit sign code, the sign (or a blank) goes right before the number.
+    has_sign: bool,
+}
+
+impl NumericFormat {
+    fn parse(fmt: &str) -> Result<Self> {
+        use NumericFormatNode::*;
+
+        fn next_is(chars: &mut Peekable<Chars<'_>>, expected: char) -> bool {
+            chars
+                .next_if(|c| c.to_ascii_uppercase() == expected)
+                .is_some()
+        }
+        let invalid = |reason: String| ExprError::InvalidParam {
+            name: "fmt",
+            reason,
+        };
+
+        let mut nodes = vec![];
+        let mut fill_mode = false;
+        let mut chars = fmt.chars().peekable();
+        while let Some(c) = chars.next() {
+            let node = match c.to_ascii_uppercase() {
+                '9' => Digit { zero: false },
+                '0' => Digit { zero: true },
+                '.' | 'D' => DecimalPoint,
+                ',' | 'G' => Group,
+                'L' => Currency,
+                'S' => {
+                    next_is(&mut chars, 'G');
+                    Sign(SignFormat::PlusMinus)
+                }
+                'M' if next_is(&mut chars, 'I') => Sign(SignFormat::Minus),
+                'P' if next_is(&mut chars, 'L') => Sign(SignFormat::Plus),
+                'F' if next_is(&mut chars, 'M') => {
+                    fill_mode = true;
+                    continue;
+                }
+                c if c.is_ascii_alphabetic() => {
+                    return Err(invalid(format!("unsupported format code \"{}\"", c)))
+                }
+                _ => Literal(c),
+            };
+            nodes.push(node);
+        }
+
+        let mut int_digits = 0;
+        let mut frac_digits = 0;
+        let mut zero_start = None;
+        let mut has_point = false;
+        let mut has_sign = false;
+        for node in &nodes {
+            match node {
+                Digit { zero } if !has_point => {
+                    if *zero && zero_start.is_none() {
+                        zero_start = Some(int_digits);
+                    }
+                    int_digits += 1;
+                }
+                Digit { .. } => frac_digits += 1,
+                DecimalPoint if has_point => {
+                    return Err(invalid("multiple decimal points".to_string()))
+                }
+                DecimalPoint => has_point = true,
+                Sign(_) if has_sign => return Err(invalid("cannot use a sign twice".to_string())),
+                Sign(_) => has_sign = true,
+                _ => {}
+            }
+        }
+        Ok(Self {
+            nodes,
+            fill_mode,
+            int_digits,
+            frac_digits,
+            zero_start: zero_start.unwrap_or(int_digits),
+            has_sign,
+        })
+    }
+
+    /// Formats `value` into `output`. `NaN`, infinities and values with more integer digits than
+    /// the mask are shown with every digit replaced by `#`.
+    fn format(&self, value: Decimal, output: &mut String) {
+        use NumericFormatNode::*;
+
+        output.clear();
+        let (negative, digits) = match value.round_dp(self.frac_digits as u32) {
+            Decimal::Normalized(d) => (
+                d.is_sign_negative() && !d.is_zero(),
+                Some(d.abs().to_string()),
+            ),
+            _ => (value == Decimal::NegativeInf, None),
+        };
+        let (int_part, frac_part) = match &digits {
+            Some(digits) => digits.split_once('.').unwrap_or((digits.as_str(), "")),
+            None => ("", ""),
+        };
+        let mut int_part = int_part.trim_start_matches('0');
+        if int_part.is_empty() && self.frac_digits == 0 {
+            int_part = "0";
+        }
+        let overflow = digits.is_none() || int_part.len() > self.int_digits;
+        let first_digit = self.int_digits.saturating_sub(int_part.len());
+        let mut int_chars = int_part.chars();
+        let mut frac_chars = frac_part.chars();
+
+        let mut int_index = 0;
+        let mut started = false;
+        let mut in_fraction = false;
+        // The length to cut `output` to in fill mode, if it ends with zeros of `9` fractional
+        // digits.
+        let mut trailing_zeros: Option<usize> = None;
+        for node in &self.nodes {
+            if !matches!(node, Digit { .. }) {
+                if let Some(len) = trailing_zeros.take() {
+                    output.truncate(len);
+                }
+            }
+            match *node {
+                Digit { .. } if !in_fraction => {
+                    let c = if overflow {
+                        Some('#')
+                    } else if int_index >= first_digit {
+                        int_chars.next()
+                    } else if int_index >= self.zero_start {
+                        Some('0')
+                    } else {
+                        None
+                    };
+                    match c {
+                        Some(c) => {
+                            self.start(negative, &mut started, output);
+                            output.push(c);
+                        }
+                        None => self.pad(output),
+                    }
+                    int_index += 1;
+                }
+                Digit { zero } => {
+                    let c = if overflow {
+                        '#'
+                    } else {
+                        frac_chars.next().unwrap_or('0')
+                    };
+                    if self.fill_mode && !zero && c == '0' {
+                        trailing_zeros.get_or_insert(output.len());
+                    } else {
+                        trailing_zeros = None;
+                    }
+                    output.push(c);
+                }
+                DecimalPoint => {
+                    self.start(negative, &mut started, output);
+                    output.push('.');
+                    in_fraction = true;
+                }
+                Group if started => output.push(','),
+                Group => self.pad(output),
+                Sign(format) => match (format, negative) {
+                    (_, true) => output.push('-'),
+                    (SignFormat::Minus, false) => self.pad(output),
+                    (SignFormat::PlusMinus | SignFormat::Plus, false) => output.push('+'),
+                },
+                Currency => output.push('$'),
+                Literal(c) => output.push(c),
+            }
+        }
+        if let Some(len) = trailing_zeros {
+            output.truncate(len);
+        }
+    }
+
+    /// Starts the number at its first digit or decimal point, placing the sign before it unless
+    /// the mask has an explicit sign code.
+    fn start(&self, negative: bool, started: &mut bool, output: &mut String) {
+        if !*started {
+            *started = true;
+            if !self.has_sign {
+                if negative {
+                    output.push('-');
+                } else {
+                    self.pad(output);
+                }
+            }
+        }
+    }
+
+    fn pad(&self, output: &mut String) {
+        if !self.fill_mode {
+            output.push(' ');
+        }
+    }
+}
+
+/// Formats each decimal in `a` by the numeric format mask `fmt`, like `to_char` in `PostgreSQL`.
+/// The mask supports the digit placeholders `9` and `0`, the decimal point `.`/`D`, the group
+/// separator `,`/`G`, the sign codes `S`, `MI`, `PL` and `SG`, the currency symbol `L` and the
+/// fill mode `FM`. It is parsed once for the whole array, so an invalid mask errors even if every
+/// value is `NULL`.
+pub fn vector_to_char_numeric(a: &DecimalArray, fmt: &str) -> Result<Utf8Array> {
+    let format = NumericFormat::parse(fmt)?;
+    let mut builder = Utf8ArrayBuilder::new(a.len());
+    let mut output = String::new();
+    for value in a.iter() {
+        match value {
+            Some(value) => {
+                format.format(value, &mut output);
+                builder.append(Some(output.as_str()));
+            }
+            None => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn to_char(values: &[&str], fmt: &str) -> Vec<Option<String>> {
+        let values = values
+            .iter()
+            .map(|v| Some(Decimal::from_str(v).unwrap()))
+            .collect_vec();
+        vector_to_char_numeric(&DecimalArray::from_slice(&values), fmt)
+            .unwrap()
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .collect()
+    }
+
+    fn strs(values: &[&str]) -> Vec<Option<String>> {
+        values.iter().map(|v| Some(v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_check_pattern() {
+        for tmpl in [
+            "YYYY-MM-DD HH24:MI:SS",
+            "yyyy-MM-dd",
+            "HH12:mi",
+            "DD/MM/YY",
+            "",
+        ] {
+            check_pattern(tmpl).unwrap();
+        }
+        for tmpl in ["YYYY-Month", "YYYY-MM-DDTHH24", "%Y"] {
+            assert!(check_pattern(tmpl).is_err(), "{}", tmpl);
+        }
+    }
+
+    #[test]
+    fn test_to_timestamp_and_date() {
+        let timestamp = |s: &str| NaiveDateTimeWrapper(s.parse().unwrap());
+        assert_eq!(
+            to_timestamp("2006-01-02 15:04:05", "YYYY-MM-DD HH24:MI:SS").unwrap(),
+            timestamp("2006-01-02T15:04:05")
+        );
+        assert_eq!(
+            to_timestamp("02/01/2006 03:04", "DD/MM/YYYY HH12:MI").unwrap(),
+            timestamp("2006-01-02T03:04:00")
+        );
+        assert_eq!(
+            to_timestamp("2006", "YYYY").unwrap(),
+            timestamp("2006-01-01T00:00:00")
+        );
+        assert_eq!(
+            to_date("2006-01-02 15:04", "YYYY-MM-DD HH24:MI").unwrap(),
+            NaiveDateWrapper("2006-01-02".parse().unwrap())
+        );
+        assert!(to_timestamp("2006-13-02", "YYYY-MM-DD").is_err());
+        assert!(to_date("not a date", "YYYY-MM-DD").is_err());
+    }
+
+    #[test]
+    fn test_to_char_numeric_grouped_integer() {
+        assert_eq!(
+            to_char(
+                &["1234567.891", "1234", "-1234", "0", "12345678"],
+                "9,999,999"
+            ),
+            strs(&[
+                " 1,234,568",
+                "     1,234",
+                "    -1,234",
+                "         0",
+                " #,###,###"
+            ])
+        );
+        assert_eq!(
+            to_char(&["1234", "-1234"], "0G000G000"),
+            strs(&[" 0,001,234", "-0,001,234"])
+        );
+    }
+
+    #[test]
+    fn test_to_char_numeric_fixed_decimal() {
+        assert_eq!(
+            to_char(&["3.14159", "-3.145", "0.5", "100"], "999.99"),
+            strs(&["   3.14", "  -3.15", "    .50", " 100.00"])
+        );
+        assert_eq!(
+            to_char(&["0.5", "-12.3"], "00.00MI"),
+            strs(&["00.50 ", "12.30-"])
+        );
+        assert_eq!(
+            to_char(&["12.3", "-12.3"], "L99D9S"),
+            strs(&["$12.3+", "$12.3-"])
+        );
+    }
+
+    #[test]
+    fn test_to_char_numeric_fill_mode() {
+        assert_eq!(
+            to_char(&["12.50", "-485", "0.1", "1"], "FM999.99"),
+            strs(&["12.5", "-485.", ".1", "1."])
+        );
+        assert_eq!(to_char(&["0.1"], "FM0.90"), strs(&["0.10"]));
+        assert_eq!(to_char(&["1234"], "FM9,999,999"), strs(&["1,234"]));
+    }
+
+    #[test]
+    fn test_to_char_numeric_null_and_invalid() {
+        let a = DecimalArray::from_slice(&[None, Some(Decimal::from(1))]);
+        let output = vector_to_char_numeric(&a, "9").unwrap();
+        assert_eq!(output.iter().collect_vec(), vec![None, Some(" 1")]);
+
+        let a = DecimalArray::from_slice(&[None, None]);
+        assert!(vector_to_char_numeric(&a, "9.99EEEE").is_err());
+        assert!(vector_to_char_numeric(&a, "9.9.9").is_err());
+    }
+}
@@ -18,6 +18,9 @@ use risingwave_common::array::{BytesGuard, BytesWriter};
 
 use crate::Result;
 
+/// Replaces each character of `s` that is in `match_str` with the character at the same position
+/// in `replace_str`. A character of `match_str` without a counterpart in `replace_str` is deleted,
+/// and only the first occurrence of a character in `match_str` counts.
 #[inline(always)]
 pub fn translate(
     s: &str,
@@ -70,6 +73,12 @@ mod tests {
                 "1234",
                 "奇点无限Singul2ri3y1232",
             ),
+            // Characters of `match_str` beyond the end of `replace_str` are deleted.
+            ("12345", "143", "ax", "a2x5"),
+            ("hello", "lo", "", "he"),
+            // Only the first mapping of a repeated character is used.
+            ("aaa", "aa", "xy", "xxx"),
+            ("hello", "", "xyz", "hello"),
         ];
 
         for (s, match_str, replace_str, expected) in cases {
@@ -0,0 +1,206 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::Float;
+use risingwave_common::array::{
+    Array, ArrayBuilder, F64Array, PrimitiveArray, PrimitiveArrayItemType,
+};
+use risingwave_common::types::OrderedF64;
+
+use crate::{ExprError, Result};
+
+/// Checks that `input` is in the domain of a trigonometric function, i.e. between `-1` and `1`
+/// for the inverse sine and cosine, or finite for the others. As in `PostgreSQL`, `NaN` is always
+/// accepted and yields `NaN`.
+#[inline(always)]
+fn check_domain(input: OrderedF64, in_domain: impl FnOnce(f64) -> bool) -> Result<f64> {
+    if input.0.is_nan() || in_domain(input.0) {
+        Ok(input.0)
+    } else {
+        Err(ExprError::NumericOutOfRange)
+    }
+}
+
+#[inline(always)]
+pub fn sin_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(check_domain(input, f64::is_finite)?.sin().into())
+}
+
+#[inline(always)]
+pub fn cos_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(check_domain(input, f64::is_finite)?.cos().into())
+}
+
+#[inline(always)]
+pub fn tan_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(check_domain(input, f64::is_finite)?.tan().into())
+}
+
+#[inline(always)]
+pub fn asin_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(check_domain(input, |x| (-1.0..=1.0).contains(&x))?
+        .asin()
+        .into())
+}
+
+#[inline(always)]
+pub fn acos_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(check_domain(input, |x| (-1.0..=1.0).contains(&x))?
+        .acos()
+        .into())
+}
+
+#[inline(always)]
+pub fn atan_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.atan().into())
+}
+
+/// Returns the angle of the point `(x, y)`, i.e. the arctangent of `y / x` in the quadrant given
+/// by the signs of both arguments.
+#[inline(always)]
+pub fn atan2_f64(y: OrderedF64, x: OrderedF64) -> Result<OrderedF64> {
+    Ok(y.0.atan2(x.0).into())
+}
+
+/// Applies `f` to each value in `a` as a double. A `NULL` yields `NULL`. A `NaN` result for an
+/// input other than `NaN` means the input is out of the domain of `f`, e.g. `asin(2)`, which is an
+/// error like a non-finite result of a float division.
+fn vector_trigonometric_primitive_float<T>(
+    a: &PrimitiveArray<T>,
+    f: impl Fn(f64) -> f64,
+) -> Result<F64Array>
+where
+    T: PrimitiveArrayItemType + Float,
+{
+    let mut builder = <F64Array as Array>::Builder::new(a.len());
+    for v in a.iter() {
+        match v {
+            Some(v) => {
+                let v = v.to_f64().unwrap();
+                let res = f(v);
+                if res.is_nan() && !v.is_nan() {
+                    return Err(ExprError::NumericOutOfRange);
+                }
+                builder.append(Some(res.into()));
+            }
+            None => builder.append(None),
+        }
+    }
+    Ok(builder.finish())
+}
+
+macro_rules! vector_trigonometric {
+    ($($name:ident => $f:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Computes `", stringify!($f), "` of each value in `a`. See")]
+            #[doc = "[`vector_trigonometric_primitive_float`] for `NULL`s and domain errors."]
+            pub fn $name<T>(a: &PrimitiveArray<T>) -> Result<F64Array>
+            where
+                T: PrimitiveArrayItemType + Float,
+            {
+                vector_trigonometric_primitive_float(a, f64::$f)
+            }
+        )*
+    };
+}
+
+vector_trigonometric! {
+    vector_sin_primitive_float => sin,
+    vector_cos_primitive_float => cos,
+    vector_tan_primitive_float => tan,
+    vector_asin_primitive_float => asin,
+    vector_acos_primitive_float => acos,
+    vector_atan_primitive_float => atan,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use risingwave_common::array::F32Array;
+
+    use super::*;
+
+    fn f(v: f64) -> OrderedF64 {
+        v.into()
+    }
+
+    fn assert_close(actual: Result<OrderedF64>, expected: f64) {
+        let actual = actual.unwrap().0;
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_trigonometric() {
+        assert_close(sin_f64(f(FRAC_PI_2)), 1.0);
+        assert_close(cos_f64(f(PI)), -1.0);
+        assert_close(tan_f64(f(FRAC_PI_4)), 1.0);
+        assert_close(asin_f64(f(1.0)), FRAC_PI_2);
+        assert_close(acos_f64(f(-1.0)), PI);
+        assert_close(atan_f64(f(1.0)), FRAC_PI_4);
+        assert_close(atan_f64(f(f64::INFINITY)), FRAC_PI_2);
+        assert_close(atan2_f64(f(1.0), f(-1.0)), 3.0 * FRAC_PI_4);
+        assert_close(atan2_f64(f(0.0), f(0.0)), 0.0);
+        assert!(sin_f64(f(f64::NAN)).unwrap().0.is_nan());
+        assert!(asin_f64(f(f64::NAN)).unwrap().0.is_nan());
+    }
+
+    #[test]
+    fn test_trigonometric_out_of_range() {
+        for result in [
+            asin_f64(f(2.0)),
+            acos_f64(f(-1.5)),
+            sin_f64(f(f64::INFINITY)),
+            cos_f64(f(f64::NEG_INFINITY)),
+            tan_f64(f(f64::INFINITY)),
+        ] {
+            assert!(matches!(result, Err(ExprError::NumericOutOfRange)));
+        }
+    }
+
+    #[test]
+    fn test_vector_trigonometric() {
+        let a = F32Array::from_slice(&[Some(0.0.into()), None, Some(1.0.into())]);
+        let res = vector_asin_primitive_float(&a).unwrap();
+        assert_eq!(res.len(), 3);
+        assert_eq!(res.value_at(0), Some(f(0.0)));
+        assert_eq!(res.value_at(1), None);
+        assert_close(Ok(res.value_at(2).unwrap()), FRAC_PI_2);
+
+        let a = F64Array::from_slice(&[Some(f(PI)), None]);
+        let res = vector_cos_primitive_float(&a).unwrap();
+        assert_close(Ok(res.value_at(0).unwrap()), -1.0);
+        assert_eq!(res.value_at(1), None);
+
+        // `NaN` passes through, but an input out of the domain is an error.
+        let a = F64Array::from_slice(&[Some(f(f64::NAN))]);
+        assert!(vector_acos_primitive_float(&a)
+            .unwrap()
+            .value_at(0)
+            .unwrap()
+            .is_nan());
+        let a = F64Array::from_slice(&[Some(f(0.5)), None, Some(f(2.0))]);
+        assert!(matches!(
+            vector_asin_primitive_float(&a),
+            Err(ExprError::NumericOutOfRange)
+        ));
+        let a = F64Array::from_slice(&[Some(f(f64::INFINITY))]);
+        assert!(vector_sin_primitive_float(&a).is_err());
+    }
+}
@@ -12,12 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_sqlparser::ast::{BinaryOperator, Expr};
 
 use crate::binder::Binder;
-use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall};
+use crate::expr::{align_types, Expr as _, ExprImpl, ExprType, FunctionCall, Literal};
 
 impl Binder {
     pub(super) fn bind_binary_op(
@@ -26,6 +27,11 @@ impl Binder {
         op: BinaryOperator,
         right: Expr,
     ) -> Result<ExprImpl> {
+        match right {
+            Expr::SomeOp(array) => return self.bind_some_all(left, op, *array, false),
+            Expr::AllOp(array) => return self.bind_some_all(left, op, *array, true),
+            _ => {}
+        }
         let bound_left = self.bind_expr(left)?;
         let bound_right = self.bind_expr(right)?;
         let func_type = match op {
@@ -54,6 +60,10 @@ impl Binder {
             BinaryOperator::PGRegexNotMatch => {
                 return self.bind_regex_not_match(bound_left, bound_right)
             }
+            BinaryOperator::SimilarTo => return self.bind_similar_to(bound_left, bound_right),
+            BinaryOperator::NotSimilarTo => {
+                return self.bind_not_similar_to(bound_left, bound_right)
+            }
 
             _ => {
                 return Err(
@@ -61,9 +71,182 @@ impl Binder {
                 )
             }
         };
+        if let ExprType::BitwiseShiftLeft | ExprType::BitwiseShiftRight = func_type {
+            Self::check_shift_count(&bound_left, &bound_right)?;
+        }
+        if is_row_function(&bound_left) && is_row_function(&bound_right) {
+            if let ExprType::Equal
+            | ExprType::NotEqual
+            | ExprType::LessThan
+            | ExprType::LessThanOrEqual
+            | ExprType::GreaterThan
+            | ExprType::GreaterThanOrEqual = func_type
+            {
+                return Self::bind_row_comparison(func_type, bound_left, bound_right);
+            }
+        }
         Ok(FunctionCall::new(func_type, vec![bound_left, bound_right])?.into())
     }
 
+    /// Validates a constant shift count against the width of the shifted integer type. A count
+    /// out of range is otherwise an overflow error at runtime.
+    fn check_shift_count(left: &ExprImpl, right: &ExprImpl) -> Result<()> {
+        let left_type = left.return_type();
+        let width = match left_type {
+            DataType::Int16 => 16,
+            DataType::Int32 => 32,
+            DataType::Int64 => 64,
+            _ => return Ok(()),
+        };
+        let ExprImpl::Literal(literal) = right else {
+            return Ok(());
+        };
+        let count = match literal.get_data() {
+            Some(ScalarImpl::Int16(count)) => *count as i64,
+            Some(ScalarImpl::Int32(count)) => *count as i64,
+            _ => return Ok(()),
+        };
+        if !(0..width).contains(&count) {
+            return Err(ErrorCode::BindError(format!(
+                "shift count {} is out of range for type {}",
+                count, left_type
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Expand a comparison between two row constructors into comparisons of their fields. `=`
+    /// and `<>` compare all fields, while the others compare lexicographically, e.g. `(a, b) <
+    /// (c, d)` becomes `a < c OR (a = c AND b < d)`.
+    pub(super) fn bind_row_comparison(
+        func_type: ExprType,
+        left: ExprImpl,
+        right: ExprImpl,
+    ) -> Result<ExprImpl> {
+        let (_, left, _) = left.into_function_call().unwrap().decompose();
+        let (_, right, _) = right.into_function_call().unwrap().decompose();
+        if left.len() != right.len() {
+            return Err(ErrorCode::BindError(
+                "unequal number of entries in row expressions".to_string(),
+            )
+            .into());
+        }
+        if left.is_empty() {
+            return Err(
+                ErrorCode::BindError("cannot compare rows of zero length".to_string()).into(),
+            );
+        }
+
+        let compare = |func_type, left: ExprImpl, right: ExprImpl| -> Result<ExprImpl> {
+            if is_row_function(&left) && is_row_function(&right) {
+                Self::bind_row_comparison(func_type, left, right)
+            } else {
+                Ok(FunctionCall::new(func_type, vec![left, right])?.into())
+            }
+        };
+        let connect = |func_type, left: ExprImpl, right: ExprImpl| -> ExprImpl {
+            FunctionCall::new_unchecked(func_type, vec![left, right], DataType::Boolean).into()
+        };
+
+        let fields = left.into_iter().zip_eq(right).collect_vec();
+        match func_type {
+            ExprType::Equal | ExprType::NotEqual => {
+                let conjunction = match func_type {
+                    ExprType::Equal => ExprType::And,
+                    _ => ExprType::Or,
+                };
+                let mut fields = fields.into_iter();
+                let (left, right) = fields.next().unwrap();
+                let mut ret = compare(func_type, left, right)?;
+                for (left, right) in fields {
+                    ret = connect(conjunction, ret, compare(func_type, left, right)?);
+                }
+                Ok(ret)
+            }
+            _ => {
+                let strict = match func_type {
+                    ExprType::LessThan | ExprType::LessThanOrEqual => ExprType::LessThan,
+                    _ => ExprType::GreaterThan,
+                };
+                // Build from the last field, which is compared with the original operator.
+                let mut fields = fields.into_iter().rev();
+                let (left, right) = fields.next().unwrap();
+                let mut ret = compare(func_type, left, right)?;
+                for (left, right) in fields {
+                    let equal = compare(ExprType::Equal, left.clone(), right.clone())?;
+                    ret = connect(
+                        ExprType::Or,
+                        compare(strict, left, right)?,
+                        connect(ExprType::And, equal, ret),
+                    );
+                }
+                Ok(ret)
+            }
+        }
+    }
+
+    /// Bind `left op SOME(array)` or `left op ALL(array)`. A literal array is folded into the
+    /// comparisons with each of its elements, connected by OR for `SOME` and by AND for `ALL`.
+    fn bind_some_all(
+        &mut self,
+        left: Expr,
+        op: BinaryOperator,
+        array: Expr,
+        all: bool,
+    ) -> Result<ExprImpl> {
+        let func_type = match op {
+            BinaryOperator::Eq => ExprType::Equal,
+            BinaryOperator::NotEq => ExprType::NotEqual,
+            BinaryOperator::Lt => ExprType::LessThan,
+            BinaryOperator::LtEq => ExprType::LessThanOrEqual,
+            BinaryOperator::Gt => ExprType::GreaterThan,
+            BinaryOperator::GtEq => ExprType::GreaterThanOrEqual,
+            _ => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("binary op with ANY/ALL: {:?}", op),
+                    112.into(),
+                )
+                .into())
+            }
+        };
+        let left = self.bind_expr(left)?;
+        let mut elements: Vec<ExprImpl> = match array {
+            Expr::Array(elements) if !elements.is_empty() => elements
+                .into_iter()
+                .map(|e| self.bind_expr(e))
+                .try_collect()?,
+            array => {
+                let array = self.bind_expr(array)?;
+                return match array.return_type() {
+                    DataType::List { .. } => Err(ErrorCode::NotImplemented(
+                        "ANY/ALL over a non-literal array".to_string(),
+                        112.into(),
+                    )
+                    .into()),
+                    data_type => Err(ErrorCode::BindError(format!(
+                        "op ANY/ALL (array) requires array on right side, but got {}",
+                        data_type
+                    ))
+                    .into()),
+                };
+            }
+        };
+        // Elements are aligned first as in `ARRAY[...]`, so an ill-typed array is still rejected.
+        align_types(elements.iter_mut())?;
+
+        let conjunction = if all { ExprType::And } else { ExprType::Or };
+        let mut elements = elements.into_iter();
+        let mut ret: ExprImpl =
+            FunctionCall::new(func_type, vec![left.clone(), elements.next().unwrap()])?.into();
+        for element in elements {
+            let compare = FunctionCall::new(func_type, vec![left.clone(), element])?.into();
+            ret = FunctionCall::new_unchecked(conjunction, vec![ret, compare], DataType::Boolean)
+                .into();
+        }
+        Ok(ret)
+    }
+
     /// Apply a NOT on top of LIKE.
     fn bind_not_like(&mut self, left: ExprImpl, right: ExprImpl) -> Result<ExprImpl> {
         Ok(FunctionCall::new(
@@ -109,4 +292,362 @@ impl Binder {
         )?
         .into())
     }
+
+    /// Bind `SIMILAR TO` as a regex match on the translated pattern. The pattern must be a
+    /// constant string, since `RegexpMatch` only accepts a constant regex.
+    fn bind_similar_to(&mut self, left: ExprImpl, right: ExprImpl) -> Result<ExprImpl> {
+        match Self::bind_similar_to_pattern(right)? {
+            Some(pattern) => self.bind_regex_match(left, pattern),
+            None => Ok(Literal::new(None, DataType::Boolean).into()),
+        }
+    }
+
+    fn bind_not_similar_to(&mut self, left: ExprImpl, right: ExprImpl) -> Result<ExprImpl> {
+        match Self::bind_similar_to_pattern(right)? {
+            Some(pattern) => self.bind_regex_not_match(left, pattern),
+            None => Ok(Literal::new(None, DataType::Boolean).into()),
+        }
+    }
+
+    /// Translate a constant `SIMILAR TO` pattern into a regex literal. Returns `None` if the
+    /// pattern is NULL.
+    fn bind_similar_to_pattern(pattern: ExprImpl) -> Result<Option<ExprImpl>> {
+        if let ExprImpl::Literal(literal) = &pattern {
+            match literal.get_data() {
+                Some(ScalarImpl::Utf8(pattern)) => {
+                    return Ok(Some(
+                        Literal::new(
+                            Some(ScalarImpl::Utf8(similar_to_regex(pattern)?)),
+                            DataType::Varchar,
+                        )
+                        .into(),
+                    ))
+                }
+                None => return Ok(None),
+                _ => {}
+            }
+        }
+        Err(ErrorCode::NotImplemented(
+            "non-constant pattern in SIMILAR TO".to_string(),
+            None.into(),
+        )
+        .into())
+    }
+}
+
+pub(super) fn is_row_function(expr: &ExprImpl) -> bool {
+    matches!(expr, ExprImpl::FunctionCall(func) if func.get_expr_type() == ExprType::Row)
+}
+
+/// Translate a SQL `SIMILAR TO` pattern into an equivalent regex, following Postgres'
+/// `similar_escape`: `%` and `_` become `.*` and `.`, `\` escapes the next character, bracket
+/// expressions are copied as is, and the whole pattern must match the entire string.
+fn similar_to_regex(pattern: &str) -> Result<String> {
+    let mut regex = String::with_capacity(pattern.len() + 10);
+    regex.push_str("(?s)^(?:");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' => regex.push(c),
+            '\\' => match chars.next() {
+                Some(escaped) => push_escaped(&mut regex, escaped),
+                None => {
+                    return Err(ErrorCode::BindError(
+                        "invalid escape string in SIMILAR TO pattern".to_string(),
+                    )
+                    .into())
+                }
+            },
+            '[' => {
+                regex.push('[');
+                // A leading `^` negates the class, and a `]` right after it is a literal.
+                if chars.next_if_eq(&'^').is_some() {
+                    regex.push('^');
+                }
+                if chars.next_if_eq(&']').is_some() {
+                    regex.push_str("\\]");
+                }
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c @ ('[' | '&' | '~')) => push_escaped(&mut regex, c),
+                        Some(c) => regex.push(c),
+                        None => {
+                            return Err(ErrorCode::BindError(
+                                "unterminated bracket expression in SIMILAR TO pattern".to_string(),
+                            )
+                            .into())
+                        }
+                    }
+                }
+                regex.push(']');
+            }
+            c => push_escaped(&mut regex, c),
+        }
+    }
+    regex.push_str(")$");
+    Ok(regex)
+}
+
+/// Push `c` into `regex` as a literal character, escaping it if it is a regex metacharacter.
+fn push_escaped(regex: &mut String, c: char) {
+    if matches!(
+        c,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '#'
+            | '&'
+            | '-'
+            | '~'
+    ) {
+        regex.push('\\');
+    }
+    regex.push(c);
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::DataType;
+    use risingwave_sqlparser::ast::{BinaryOperator, Expr, Value};
+
+    use super::similar_to_regex;
+    use crate::binder::test_utils::mock_binder;
+    use crate::expr::{Expr as _, ExprImpl, ExprType};
+
+    fn row(values: &[&str]) -> Expr {
+        Expr::Row(
+            values
+                .iter()
+                .map(|v| Expr::Value(Value::Number(v.to_string())))
+                .collect(),
+        )
+    }
+
+    fn compare(left: Expr, op: BinaryOperator, right: Expr) -> Expr {
+        Expr::BinaryOp {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+        }
+    }
+
+    /// Returns the type of a function call and its inputs.
+    fn decompose(expr: &ExprImpl) -> (ExprType, &[ExprImpl]) {
+        let ExprImpl::FunctionCall(func) = expr else {
+            panic!("expect a function call, but got {:?}", expr);
+        };
+        (func.get_expr_type(), func.inputs())
+    }
+
+    #[tokio::test]
+    async fn test_bind_row_comparison() {
+        let mut binder = mock_binder();
+
+        // (1, 2) = (3, 4) => (1 = 3) AND (2 = 4)
+        let expr = binder
+            .bind_expr(compare(
+                row(&["1", "2"]),
+                BinaryOperator::Eq,
+                row(&["3", "4"]),
+            ))
+            .unwrap();
+        let (func_type, inputs) = decompose(&expr);
+        assert_eq!(func_type, ExprType::And);
+        assert_eq!(decompose(&inputs[0]).0, ExprType::Equal);
+        assert_eq!(decompose(&inputs[1]).0, ExprType::Equal);
+
+        // (1, 2) < (3, 4) => (1 < 3) OR ((1 = 3) AND (2 < 4))
+        let expr = binder
+            .bind_expr(compare(
+                row(&["1", "2"]),
+                BinaryOperator::Lt,
+                row(&["3", "4"]),
+            ))
+            .unwrap();
+        let (func_type, inputs) = decompose(&expr);
+        assert_eq!(func_type, ExprType::Or);
+        assert_eq!(decompose(&inputs[0]).0, ExprType::LessThan);
+        let (func_type, inputs) = decompose(&inputs[1]);
+        assert_eq!(func_type, ExprType::And);
+        assert_eq!(decompose(&inputs[0]).0, ExprType::Equal);
+        assert_eq!(decompose(&inputs[1]).0, ExprType::LessThan);
+
+        // (1, 2) = (3, 4, 5)
+        let err = binder
+            .bind_expr(compare(
+                row(&["1", "2"]),
+                BinaryOperator::Eq,
+                row(&["3", "4", "5"]),
+            ))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Bind error: unequal number of entries in row expressions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_some_all() {
+        let mut binder = mock_binder();
+        let number = |v: &str| Expr::Value(Value::Number(v.to_string()));
+        let array = |values: &[&str]| Expr::Array(values.iter().map(|v| number(v)).collect());
+
+        // 1 = ANY(ARRAY[1, 2, 3]) => ((1 = 1) OR (1 = 2)) OR (1 = 3)
+        let expr = binder
+            .bind_expr(compare(
+                number("1"),
+                BinaryOperator::Eq,
+                Expr::SomeOp(Box::new(array(&["1", "2", "3"]))),
+            ))
+            .unwrap();
+        let (func_type, inputs) = decompose(&expr);
+        assert_eq!(func_type, ExprType::Or);
+        assert_eq!(decompose(&inputs[1]).0, ExprType::Equal);
+        let (func_type, inputs) = decompose(&inputs[0]);
+        assert_eq!(func_type, ExprType::Or);
+        assert_eq!(decompose(&inputs[0]).0, ExprType::Equal);
+        assert_eq!(decompose(&inputs[1]).0, ExprType::Equal);
+
+        // 5 > ALL(ARRAY[1, 2]) => (5 > 1) AND (5 > 2)
+        let expr = binder
+            .bind_expr(compare(
+                number("5"),
+                BinaryOperator::Gt,
+                Expr::AllOp(Box::new(array(&["1", "2"]))),
+            ))
+            .unwrap();
+        let (func_type, inputs) = decompose(&expr);
+        assert_eq!(func_type, ExprType::And);
+        assert_eq!(decompose(&inputs[0]).0, ExprType::GreaterThan);
+        assert_eq!(decompose(&inputs[1]).0, ExprType::GreaterThan);
+
+        // The element type must be comparable with the left side.
+        assert!(binder
+            .bind_expr(compare(
+                number("1"),
+                BinaryOperator::Eq,
+                Expr::SomeOp(Box::new(Expr::Array(vec![Expr::Value(Value::Boolean(
+                    true
+                ))]))),
+            ))
+            .is_err());
+
+        let err = binder
+            .bind_expr(compare(
+                number("1"),
+                BinaryOperator::Eq,
+                Expr::SomeOp(Box::new(number("1"))),
+            ))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Bind error: op ANY/ALL (array) requires array on right side, but got integer"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_bitwise_op() {
+        let mut binder = mock_binder();
+        let number = |v: &str| Expr::Value(Value::Number(v.to_string()));
+
+        for (op, expected) in [
+            (BinaryOperator::BitwiseAnd, ExprType::BitwiseAnd),
+            (BinaryOperator::BitwiseOr, ExprType::BitwiseOr),
+            (BinaryOperator::PGBitwiseXor, ExprType::BitwiseXor),
+            (
+                BinaryOperator::PGBitwiseShiftLeft,
+                ExprType::BitwiseShiftLeft,
+            ),
+            (
+                BinaryOperator::PGBitwiseShiftRight,
+                ExprType::BitwiseShiftRight,
+            ),
+        ] {
+            let expr = binder
+                .bind_expr(compare(number("12"), op, number("3")))
+                .unwrap();
+            assert_eq!(decompose(&expr).0, expected);
+            assert_eq!(expr.return_type(), DataType::Int32);
+        }
+
+        // Bitwise operators are only defined on integers.
+        assert!(binder
+            .bind_expr(compare(
+                number("1.5"),
+                BinaryOperator::BitwiseAnd,
+                number("1")
+            ))
+            .is_err());
+
+        // A constant shift count must be less than the width of the shifted type.
+        let err = binder
+            .bind_expr(compare(
+                number("1"),
+                BinaryOperator::PGBitwiseShiftLeft,
+                number("32"),
+            ))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Bind error: shift count 32 is out of range for type integer"
+        );
+        assert!(binder
+            .bind_expr(compare(
+                number("1"),
+                BinaryOperator::PGBitwiseShiftRight,
+                number("-1"),
+            ))
+            .is_err());
+        let expr = binder
+            .bind_expr(compare(
+                number("4294967296"),
+                BinaryOperator::PGBitwiseShiftLeft,
+                number("31"),
+            ))
+            .unwrap();
+        assert_eq!(expr.return_type(), DataType::Int64);
+    }
+
+    #[test]
+    fn test_similar_to_regex() {
+        let cases = [
+            ("abc", "(?s)^(?:abc)$"),
+            ("a%", "(?s)^(?:a.*)$"),
+            ("_b_", "(?s)^(?:.b.)$"),
+            ("%(b|d)%", "(?s)^(?:.*(b|d).*)$"),
+            ("a+b*c?", "(?s)^(?:a+b*c?)$"),
+            ("a{2,3}", "(?s)^(?:a{2,3})$"),
+            ("[a-c]%", "(?s)^(?:[a-c].*)$"),
+            ("[^]a]", "(?s)^(?:[^\\]a])$"),
+            ("[]a]", "(?s)^(?:[\\]a])$"),
+            ("a.b$c^", "(?s)^(?:a\\.b\\$c\\^)$"),
+            ("100\\%", "(?s)^(?:100%)$"),
+            ("a\\_b", "(?s)^(?:a_b)$"),
+            ("a\\.b", "(?s)^(?:a\\.b)$"),
+            ("\\\\", "(?s)^(?:\\\\)$"),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(similar_to_regex(pattern).unwrap(), expected, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_similar_to_regex_invalid() {
+        assert!(similar_to_regex("abc\\").is_err());
+        assert!(similar_to_regex("[abc").is_err());
+        assert!(similar_to_regex("[]").is_err());
+    }
 }
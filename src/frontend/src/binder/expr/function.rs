@@ -18,8 +18,12 @@ use std::str::FromStr;
 use itertools::Itertools;
 use risingwave_common::catalog::{DEFAULT_SCHEMA_NAME, PG_CATALOG_SCHEMA_NAME};
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_expr::expr::AggKind;
+use risingwave_expr::vector_op::date_trunc::TruncField;
+use risingwave_expr::vector_op::format::Formatter;
+use risingwave_expr::vector_op::to_char::check_pattern;
+use risingwave_expr::ExprError;
 use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr, WindowSpec};
 
 use crate::binder::bind_context::Clause;
@@ -31,7 +35,9 @@ use crate::expr::{
 use crate::utils::Condition;
 
 impl Binder {
-    pub(super) fn bind_function(&mut self, f: Function) -> Result<ExprImpl> {
+    pub(super) fn bind_function(&mut self, mut f: Function) -> Result<ExprImpl> {
+        // An unquoted function name is case-insensitive and lowercased here, while a quoted one is
+        // kept as is, so that `"Sum"(x)` is not `sum(x)`.
         let function_name = if f.name.0.len() == 1 {
             f.name.0.get(0).unwrap().real_value()
         } else if f.name.0.len() == 2 {
@@ -52,6 +58,8 @@ impl Binder {
             )
             .into());
         };
+        let function_name = Self::normalize_function_name(function_name);
+        f.args = Self::reorder_named_args(&function_name, std::mem::take(&mut f.args))?;
 
         // agg calls
         if let Ok(kind) = function_name.parse() {
@@ -121,6 +129,23 @@ impl Binder {
             "ceil" => ExprType::Ceil,
             "floor" => ExprType::Floor,
             "abs" => ExprType::Abs,
+            "gcd" => ExprType::Gcd,
+            "lcm" => ExprType::Lcm,
+            "factorial" => ExprType::Factorial,
+            "power" => ExprType::Pow,
+            "sqrt" => ExprType::Sqrt,
+            "sin" => ExprType::Sin,
+            "cos" => ExprType::Cos,
+            "tan" => ExprType::Tan,
+            "asin" => ExprType::Asin,
+            "acos" => ExprType::Acos,
+            "atan" => ExprType::Atan,
+            "atan2" => ExprType::Atan2,
+            // date/time
+            "date_trunc" => {
+                Self::check_date_trunc_field(&inputs)?;
+                ExprType::DateTrunc
+            }
             // string
             "substr" => ExprType::Substr,
             "length" => ExprType::Length,
@@ -128,29 +153,75 @@ impl Binder {
             "lower" => ExprType::Lower,
             "trim" => ExprType::Trim,
             "replace" => ExprType::Replace,
+            "translate" => ExprType::Translate,
             "overlay" => ExprType::Overlay,
             "position" => ExprType::Position,
+            "strpos" => ExprType::Position,
+            "starts_with" => ExprType::StartsWith,
             "ltrim" => ExprType::Ltrim,
             "rtrim" => ExprType::Rtrim,
             "md5" => ExprType::Md5,
-            "to_char" => ExprType::ToChar,
+            "to_char" => {
+                if inputs.first().map(|input| input.return_type()) == Some(DataType::Timestamp) {
+                    Self::check_datetime_pattern(&inputs)?;
+                }
+                ExprType::ToChar
+            }
+            "to_date" => {
+                Self::check_datetime_pattern(&inputs)?;
+                ExprType::ToDate
+            }
+            "to_timestamp" => {
+                Self::check_datetime_pattern(&inputs)?;
+                ExprType::ToTimestamp
+            }
             "concat" => {
                 inputs = Self::rewrite_concat_to_concat_ws(inputs)?;
                 ExprType::ConcatWs
             }
             "concat_ws" => ExprType::ConcatWs,
+            "format" => {
+                Self::check_format_args(&inputs)?;
+                ExprType::Format
+            }
             "split_part" => ExprType::SplitPart,
             "char_length" => ExprType::CharLength,
-            "character_length" => ExprType::CharLength,
             "repeat" => ExprType::Repeat,
+            "reverse" => ExprType::Reverse,
+            "left" => ExprType::Left,
+            "right" => ExprType::Right,
             "ascii" => ExprType::Ascii,
+            "chr" => ExprType::Chr,
             "octet_length" => ExprType::OctetLength,
             "bit_length" => ExprType::BitLength,
             "regexp_match" => ExprType::RegexpMatch,
+            "regexp_replace" => {
+                Self::check_regexp_flags(&inputs)?;
+                ExprType::RegexpReplace
+            }
+            "encode" | "decode" => {
+                Self::check_bytea_encoding(&function_name, &inputs)?;
+                return Err(ErrorCode::NotImplemented(
+                    format!("{}: bytea type is not supported yet", function_name),
+                    None.into(),
+                )
+                .into());
+            }
+            "sha256" => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("{}: bytea type is not supported yet", function_name),
+                    None.into(),
+                )
+                .into());
+            }
             // array
             "array_cat" => ExprType::ArrayCat,
             "array_append" => ExprType::ArrayAppend,
             "array_prepend" => ExprType::ArrayPrepend,
+            "string_to_array" => ExprType::StringToArray,
+            "array_to_string" => ExprType::ArrayToString,
+            "array_length" => ExprType::ArrayLength,
+            "cardinality" => ExprType::Cardinality,
             // System information operations.
             "pg_typeof" if inputs.len() == 1 => {
                 let input = &inputs[0];
@@ -215,6 +286,12 @@ impl Binder {
             .map(|arg| self.bind_function_arg(arg))
             .flatten_ok()
             .try_collect()?;
+        if inputs.iter().any(|input| input.has_table_function()) {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "aggregate function calls cannot contain table function calls".to_string(),
+            )
+            .into());
+        }
         if f.distinct {
             match &kind {
                 AggKind::Count if inputs.is_empty() => {
@@ -257,13 +334,6 @@ impl Binder {
                     ))
                     .into());
                 }
-                if expr.has_subquery() {
-                    return Err(ErrorCode::NotImplemented(
-                        "subquery in filter clause".to_string(),
-                        None.into(),
-                    )
-                    .into());
-                }
                 if expr.has_agg_call() {
                     return Err(ErrorCode::NotImplemented(
                         "aggregation function in filter clause".to_string(),
@@ -335,6 +405,115 @@ impl Binder {
         Ok(WindowFunction::new(window_function_type, partition_by, order_by, inputs)?.into())
     }
 
+    /// Normalizes an alias of a function to its canonical name, e.g. `ceiling` to `ceil`, so that
+    /// only canonical names are matched when binding.
+    fn normalize_function_name(function_name: String) -> String {
+        let canonical = match function_name.as_str() {
+            "ceiling" => "ceil",
+            "pow" => "power",
+            "character_length" => "char_length",
+            _ => return function_name,
+        };
+        canonical.to_string()
+    }
+
+    /// The parameter names of the functions accepting named arguments, e.g.
+    /// `date_trunc(field => 'day', source => ts)`.
+    fn function_param_names(function_name: &str) -> Option<&'static [&'static str]> {
+        let param_names: &[&str] = match function_name {
+            "date_trunc" => &["field", "source"],
+            "to_char" => &["value", "format"],
+            "to_date" | "to_timestamp" => &["text", "format"],
+            "substr" => &["string", "start", "count"],
+            "split_part" => &["string", "delimiter", "n"],
+            _ => return None,
+        };
+        Some(param_names)
+    }
+
+    /// Reorders named arguments `name => value` to their positions, so that only positional
+    /// arguments are left. Like in `PostgreSQL`, named arguments may follow positional ones but not
+    /// the other way around, and only trailing arguments can be omitted.
+    fn reorder_named_args(function_name: &str, args: Vec<FunctionArg>) -> Result<Vec<FunctionArg>> {
+        if args
+            .iter()
+            .all(|arg| matches!(arg, FunctionArg::Unnamed(_)))
+        {
+            return Ok(args);
+        }
+        let Some(param_names) = Self::function_param_names(function_name) else {
+            return Err(ErrorCode::BindError(format!(
+                "function {} does not accept named arguments",
+                function_name
+            ))
+            .into());
+        };
+
+        let mut reordered = vec![None; param_names.len()];
+        let mut has_named = false;
+        for (i, arg) in args.into_iter().enumerate() {
+            let position = match &arg {
+                FunctionArg::Unnamed(_) if has_named => {
+                    return Err(ErrorCode::BindError(
+                        "positional argument cannot follow named argument".to_string(),
+                    )
+                    .into());
+                }
+                FunctionArg::Unnamed(_) => i,
+                FunctionArg::Named { name, .. } => {
+                    has_named = true;
+                    let name = name.real_value();
+                    param_names
+                        .iter()
+                        .position(|param_name| *param_name == name)
+                        .ok_or_else(|| {
+                            ErrorCode::BindError(format!(
+                                "function {} has no parameter named \"{}\"",
+                                function_name, name
+                            ))
+                        })?
+                }
+            };
+            let Some(slot) = reordered.get_mut(position) else {
+                return Err(ErrorCode::BindError(format!(
+                    "function {} takes at most {} arguments",
+                    function_name,
+                    param_names.len()
+                ))
+                .into());
+            };
+            if slot.is_some() {
+                return Err(ErrorCode::BindError(format!(
+                    "argument name \"{}\" used more than once",
+                    param_names[position]
+                ))
+                .into());
+            }
+            *slot = Some(FunctionArg::Unnamed(arg.get_expr()));
+        }
+
+        let len = reordered
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |i| i + 1);
+        reordered
+            .into_iter()
+            .take(len)
+            .enumerate()
+            .map(|(i, arg)| {
+                arg.ok_or_else(|| {
+                    ErrorCode::BindError(format!(
+                        "missing argument \"{}\" of function {}",
+                        param_names[i], function_name
+                    ))
+                    .into()
+                })
+            })
+            .collect()
+    }
+
+    /// Rewrites `concat(args...)` to `concat_ws('', args...)`, which casts each argument to its
+    /// text output and skips `NULL`s, so that only all-`NULL` arguments yield an empty string.
     fn rewrite_concat_to_concat_ws(inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
         if inputs.is_empty() {
             Err(ErrorCode::BindError(
@@ -364,6 +543,105 @@ impl Binder {
         }
     }
 
+    /// Validates the encoding argument of `encode(bytea, text)` and `decode(text, text)`, which
+    /// can be `hex`, `base64` or `escape`. A non-constant encoding can only be checked at runtime.
+    fn check_bytea_encoding(function_name: &str, inputs: &[ExprImpl]) -> Result<()> {
+        if inputs.len() != 2 {
+            return Err(ErrorCode::BindError(format!(
+                "{} function must contain 2 arguments",
+                function_name
+            ))
+            .into());
+        }
+        if let ExprImpl::Literal(literal) = &inputs[1] {
+            if let Some(ScalarImpl::Utf8(encoding)) = literal.get_data() {
+                if !["hex", "base64", "escape"].contains(&encoding.to_lowercase().as_str()) {
+                    return Err(ErrorCode::BindError(format!(
+                        "unrecognized encoding: \"{}\"",
+                        encoding
+                    ))
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the optional flags argument of `regexp_replace(text, pattern, replacement,
+    /// flags)`. Non-constant flags can only be checked at runtime.
+    fn check_regexp_flags(inputs: &[ExprImpl]) -> Result<()> {
+        if let Some(ExprImpl::Literal(literal)) = inputs.get(3) {
+            if let Some(ScalarImpl::Utf8(flags)) = literal.get_data() {
+                if let Some(flag) = flags.chars().find(|flag| !matches!(flag, 'g' | 'i')) {
+                    return Err(ErrorCode::BindError(format!(
+                        "invalid regular expression option: \"{}\"",
+                        flag
+                    ))
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates a constant format string of `format(text, args...)`, and that there are enough
+    /// arguments for its specifiers.
+    fn check_format_args(inputs: &[ExprImpl]) -> Result<()> {
+        if let Some(ExprImpl::Literal(literal)) = inputs.first() {
+            if let Some(ScalarImpl::Utf8(format)) = literal.get_data() {
+                let formatter = Formatter::parse(format).map_err(Self::param_bind_error)?;
+                if formatter.arg_count() > inputs.len() - 1 {
+                    return Err(
+                        ErrorCode::BindError("too few arguments for format()".to_string()).into(),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates a constant pattern of `to_char(timestamp, text)`, `to_date(text, text)` or
+    /// `to_timestamp(text, text)`.
+    fn check_datetime_pattern(inputs: &[ExprImpl]) -> Result<()> {
+        if let Some(ExprImpl::Literal(literal)) = inputs.get(1) {
+            if let Some(ScalarImpl::Utf8(tmpl)) = literal.get_data() {
+                check_pattern(tmpl).map_err(Self::param_bind_error)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reports an invalid constant argument found by a check of the expression crate.
+    fn param_bind_error(e: ExprError) -> ErrorCode {
+        match e {
+            ExprError::InvalidParam { reason, .. } => ErrorCode::BindError(reason),
+            e => ErrorCode::BindError(e.to_string()),
+        }
+    }
+
+    /// Validates the field argument of `date_trunc(text, timestamp)`. A non-constant field can
+    /// only be checked at runtime.
+    fn check_date_trunc_field(inputs: &[ExprImpl]) -> Result<()> {
+        if inputs.len() != 2 {
+            return Err(ErrorCode::BindError(
+                "date_trunc function must contain 2 arguments".to_string(),
+            )
+            .into());
+        }
+        if let ExprImpl::Literal(literal) = &inputs[0] {
+            if let Some(ScalarImpl::Utf8(field)) = literal.get_data() {
+                if TruncField::from_str(field).is_err() {
+                    return Err(ErrorCode::BindError(format!(
+                        "timestamp units \"{}\" not recognized",
+                        field
+                    ))
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn rewrite_two_bool_inputs(mut inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
         if inputs.len() != 2 {
             return Err(
@@ -416,14 +694,16 @@ impl Binder {
     fn ensure_table_function_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             match clause {
-                Clause::Where | Clause::Values => {
+                // Like in `PostgreSQL`, a table function returning a set of rows makes no sense
+                // where a single value is expected for each row or group.
+                Clause::Where | Clause::Values | Clause::Having => {
                     return Err(ErrorCode::InvalidInputSyntax(format!(
                         "table functions are not allowed in {}",
                         clause
                     ))
                     .into());
                 }
-                Clause::GroupBy | Clause::Having | Clause::Filter => {}
+                Clause::GroupBy | Clause::Filter => {}
             }
         }
         Ok(())
@@ -447,7 +727,11 @@ impl Binder {
     ) -> Result<Vec<ExprImpl>> {
         match arg {
             FunctionArg::Unnamed(expr) => self.bind_function_expr_arg(expr),
-            FunctionArg::Named { .. } => todo!(),
+            FunctionArg::Named { name, .. } => Err(ErrorCode::BindError(format!(
+                "unexpected named argument \"{}\"",
+                name.real_value()
+            ))
+            .into()),
         }
     }
 }
@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use itertools::{zip_eq, Itertools};
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
+use risingwave_expr::vector_op::cast::is_relative_datetime;
 use risingwave_sqlparser::ast::{
     BinaryOperator, DataType as AstDataType, DateTimeField, Expr, Function, ObjectName, Query,
-    StructField, TrimWhereField, UnaryOperator,
+    StructField, TrimWhereField, UnaryOperator, Value,
 };
 
+use self::binary_op::is_row_function;
 use crate::binder::Binder;
 use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall, SubqueryKind};
 
@@ -36,11 +40,9 @@ impl Binder {
         match expr {
             // literal
             Expr::Value(v) => Ok(ExprImpl::Literal(Box::new(self.bind_value(v)?))),
-            Expr::TypedString { data_type, value } => {
-                let s: ExprImpl = self.bind_string(value)?.into();
-                s.cast_explicit(bind_data_type(&data_type)?)
-            }
+            Expr::TypedString { data_type, value } => self.bind_typed_string(data_type, value),
             Expr::Row(exprs) => self.bind_row(exprs),
+            Expr::Parameter { index } => self.bind_parameter(index),
             // input ref
             Expr::Identifier(ident) => {
                 if ["session_user", "current_schema"]
@@ -76,7 +78,11 @@ impl Binder {
                 negated,
             } => self.bind_in_subquery(*expr, *subquery, negated),
             // special syntax (except date/time or string)
-            Expr::Cast { expr, data_type } => self.bind_cast(*expr, data_type),
+            Expr::Cast { expr, data_type } => self.bind_cast(*expr, data_type, None),
+            Expr::Collate { expr, collation } => match *expr {
+                Expr::Cast { expr, data_type } => self.bind_cast(*expr, data_type, Some(collation)),
+                expr => bind_collate(self.bind_expr(expr)?, &collation),
+            },
             Expr::IsNull(expr) => self.bind_is_operator(ExprType::IsNull, *expr),
             Expr::IsNotNull(expr) => self.bind_is_operator(ExprType::IsNotNull, *expr),
             Expr::IsTrue(expr) => self.bind_is_operator(ExprType::IsTrue, *expr),
@@ -151,27 +157,38 @@ impl Binder {
         negated: bool,
     ) -> Result<ExprImpl> {
         let left = self.bind_expr(expr)?;
-        let mut bound_expr_list = vec![left.clone()];
-        let mut non_const_exprs = vec![];
-        for elem in list {
-            let expr = self.bind_expr(elem)?;
-            match expr.is_const() {
-                true => bound_expr_list.push(expr),
-                false => non_const_exprs.push(expr),
+        let bound_list: Vec<ExprImpl> = list
+            .into_iter()
+            .map(|elem| self.bind_expr(elem))
+            .try_collect()?;
+        let ret = match Self::bind_in_literal_set(&left, &bound_list) {
+            Some(ret) => ret,
+            None if is_row_function(&left) => Self::bind_row_in_list(left, bound_list)?,
+            None => {
+                let mut bound_expr_list = vec![left.clone()];
+                let mut non_const_exprs = vec![];
+                for expr in bound_list {
+                    match expr.is_const() {
+                        true => bound_expr_list.push(expr),
+                        false => non_const_exprs.push(expr),
+                    }
+                }
+                let mut ret = FunctionCall::new(ExprType::In, bound_expr_list)?.into();
+                // Non-const exprs are not part of IN-expr in backend and rewritten into
+                // OR-Equal-exprs.
+                for expr in non_const_exprs {
+                    ret = FunctionCall::new(
+                        ExprType::Or,
+                        vec![
+                            ret,
+                            FunctionCall::new(ExprType::Equal, vec![left.clone(), expr])?.into(),
+                        ],
+                    )?
+                    .into();
+                }
+                ret
             }
-        }
-        let mut ret = FunctionCall::new(ExprType::In, bound_expr_list)?.into();
-        // Non-const exprs are not part of IN-expr in backend and rewritten into OR-Equal-exprs.
-        for expr in non_const_exprs {
-            ret = FunctionCall::new(
-                ExprType::Or,
-                vec![
-                    ret,
-                    FunctionCall::new(ExprType::Equal, vec![left.clone(), expr])?.into(),
-                ],
-            )?
-            .into();
-        }
+        };
         if negated {
             Ok(FunctionCall::new_unchecked(ExprType::Not, vec![ret], DataType::Boolean).into())
         } else {
@@ -179,6 +196,52 @@ impl Binder {
         }
     }
 
+    /// Expand `(a, b) IN ((1, 2), (3, 4))` into `(a, b) = (1, 2) OR (a, b) = (3, 4)`, where each
+    /// row equality is in turn expanded into its fields.
+    fn bind_row_in_list(left: ExprImpl, list: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let mut ret: Option<ExprImpl> = None;
+        for expr in list {
+            if !is_row_function(&expr) {
+                return Err(ErrorCode::BindError(format!(
+                    "IN list of a row expression expects row elements, but got {}",
+                    expr.return_type()
+                ))
+                .into());
+            }
+            let equal = Self::bind_row_comparison(ExprType::Equal, left.clone(), expr)?;
+            ret = Some(match ret {
+                Some(ret) => {
+                    FunctionCall::new_unchecked(ExprType::Or, vec![ret, equal], DataType::Boolean)
+                        .into()
+                }
+                None => equal,
+            });
+        }
+        Ok(ret.expect("IN list should not be empty"))
+    }
+
+    /// Fast path of `bind_in_list` when every element is a literal of the same type as `left`.
+    /// The literals are deduplicated and passed to `In` without any type alignment, and the
+    /// backend evaluates them as a hash set. Returns `None` if the list doesn't qualify.
+    fn bind_in_literal_set(left: &ExprImpl, list: &[ExprImpl]) -> Option<ExprImpl> {
+        let left_type = left.return_type();
+        let mut set = HashSet::with_capacity(list.len());
+        let mut inputs = Vec::with_capacity(list.len() + 1);
+        inputs.push(left.clone());
+        for expr in list {
+            let ExprImpl::Literal(literal) = expr else {
+                return None;
+            };
+            if literal.return_type() != left_type {
+                return None;
+            }
+            if set.insert(literal.get_data().clone()) {
+                inputs.push(expr.clone());
+            }
+        }
+        Some(FunctionCall::new_unchecked(ExprType::In, inputs, DataType::Boolean).into())
+    }
+
     pub(super) fn bind_in_subquery(
         &mut self,
         expr: Expr,
@@ -202,6 +265,9 @@ impl Binder {
             UnaryOperator::Not => ExprType::Not,
             UnaryOperator::Minus => ExprType::Neg,
             UnaryOperator::PGBitwiseNot => ExprType::BitwiseNot,
+            UnaryOperator::PGPostfixFactorial | UnaryOperator::PGPrefixFactorial => {
+                ExprType::Factorial
+            }
             UnaryOperator::Plus => {
                 return self.rewrite_positive(expr);
             }
@@ -374,25 +440,59 @@ impl Binder {
         Ok(func_call?.into())
     }
 
-    pub(super) fn bind_cast(&mut self, expr: Expr, data_type: AstDataType) -> Result<ExprImpl> {
-        let lhs = if matches!(&expr, Expr::Array(elements) if elements.is_empty())
-            && matches!(&data_type, AstDataType::Array(_))
-        {
+    /// Binds `CAST(expr AS data_type)`, applying `collation` to the result if there is one.
+    pub(super) fn bind_cast(
+        &mut self,
+        expr: Expr,
+        data_type: AstDataType,
+        collation: Option<ObjectName>,
+    ) -> Result<ExprImpl> {
+        let lhs = match (expr, &data_type) {
             // The subexpr `array[]` is invalid and cannot bind by itself without a parent cast.
             // So we handle `array[]::T[]`/`cast(array[] as T[])` as a whole here.
-            FunctionCall::new_unchecked(
-                ExprType::Array,
-                vec![],
-                // Treat `array[]` as `varchar[]` temporarily before applying cast.
-                DataType::List {
-                    datatype: Box::new(DataType::Varchar),
-                },
-            )
-            .into()
-        } else {
-            self.bind_expr(expr)?
+            (Expr::Array(elements), AstDataType::Array(_)) if elements.is_empty() => {
+                FunctionCall::new_unchecked(
+                    ExprType::Array,
+                    vec![],
+                    // Treat `array[]` as `varchar[]` temporarily before applying cast.
+                    DataType::List {
+                        datatype: Box::new(DataType::Varchar),
+                    },
+                )
+                .into()
+            }
+            // `'now'` and the like depend on the statement time, so they are resolved here like
+            // `TIMESTAMP 'now'` rather than cast at runtime.
+            (
+                Expr::Value(Value::SingleQuotedString(value)),
+                AstDataType::Date | AstDataType::Time(_) | AstDataType::Timestamp(_),
+            ) if is_relative_datetime(&value) => {
+                self.bind_typed_string(data_type.clone(), value)?
+            }
+            (expr, _) => self.bind_expr(expr)?,
         };
-        lhs.cast_explicit(bind_data_type(&data_type)?)
+        let cast = lhs.cast_explicit(bind_data_type(&data_type)?)?;
+        match collation {
+            Some(collation) => bind_collate(cast, &collation),
+            None => Ok(cast),
+        }
+    }
+}
+
+/// Applies `collation` to a bound text expression. Strings are always compared byte-wise, so only
+/// the collations with that behavior are supported, and they leave the expression unchanged.
+fn bind_collate(expr: ExprImpl, collation: &ObjectName) -> Result<ExprImpl> {
+    let data_type = expr.return_type();
+    if data_type != DataType::Varchar {
+        return Err(ErrorCode::BindError(format!(
+            "collations are not supported by type {}",
+            data_type
+        ))
+        .into());
+    }
+    match collation.real_value().as_str() {
+        "C" | "POSIX" | "default" => Ok(expr),
+        _ => Err(ErrorCode::NotImplemented(format!("collation {}", collation), None.into()).into()),
     }
 }
 
@@ -471,6 +571,17 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
                 "float4" => DataType::Float32,
                 "float8" => DataType::Float64,
                 "timestamptz" => DataType::Timestampz,
+                name @ ("point" | "line" | "lseg" | "box" | "path" | "polygon" | "circle") => {
+                    return Err(ErrorCode::NotImplemented(
+                        format!(
+                            "geometric type {} is not supported, please store its coordinates in \
+                             numeric columns instead",
+                            name
+                        ),
+                        None.into(),
+                    )
+                    .into())
+                }
                 _ => return Err(new_err().into()),
             }
         }
@@ -478,3 +589,629 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
     };
     Ok(data_type)
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::Row;
+    use risingwave_common::error::{ErrorCode, Result};
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_expr::expr::{build_from_prost, AggKind};
+    use risingwave_expr::vector_op::cast::{str_to_date_at, str_to_time_at};
+    use risingwave_sqlparser::ast::{DataType as AstDataType, Expr, Ident, ObjectName, Value};
+    use risingwave_sqlparser::parser::Parser;
+    use risingwave_sqlparser::tokenizer::Tokenizer;
+
+    use super::bind_data_type;
+    use crate::binder::test_utils::mock_binder;
+    use crate::binder::{Binder, BoundSetExpr, BoundStatement};
+    use crate::expr::{
+        Expr as _, ExprImpl, ExprType, Parameter, ParameterTypes, TableFunctionType,
+    };
+    use crate::optimizer::property::Direction;
+    use crate::test_utils::LocalFrontend;
+
+    fn int_in_list(negated: bool) -> Expr {
+        Expr::InList {
+            expr: Box::new(Expr::Value(Value::Number("42".to_string()))),
+            list: (0..500)
+                .map(|i| Expr::Value(Value::Number(i.to_string())))
+                .collect(),
+            negated,
+        }
+    }
+
+    /// Parses `sql` as an expression and binds it.
+    fn bind_sql(binder: &mut Binder, sql: &str) -> Result<ExprImpl> {
+        let tokens = Tokenizer::new(sql).tokenize().unwrap();
+        let expr = Parser::new(tokens).parse_expr().unwrap();
+        binder.bind_expr(expr)
+    }
+
+    #[tokio::test]
+    async fn test_bind_in_list_literal_set() {
+        let mut binder = mock_binder();
+
+        let ExprImpl::FunctionCall(func_call) = binder.bind_expr(int_in_list(false)).unwrap()
+        else {
+            panic!("expect a function call");
+        };
+        assert_eq!(func_call.get_expr_type(), ExprType::In);
+        assert_eq!(func_call.inputs().len(), 501);
+        assert!(func_call.inputs()[1..]
+            .iter()
+            .all(|input| matches!(input, ExprImpl::Literal(_))));
+
+        let ExprImpl::FunctionCall(not) = binder.bind_expr(int_in_list(true)).unwrap() else {
+            panic!("expect a function call");
+        };
+        assert_eq!(not.get_expr_type(), ExprType::Not);
+        let ExprImpl::FunctionCall(func_call) = &not.inputs()[0] else {
+            panic!("expect a function call");
+        };
+        assert_eq!(func_call.get_expr_type(), ExprType::In);
+        assert_eq!(func_call.inputs().len(), 501);
+    }
+
+    #[tokio::test]
+    async fn test_bind_in_list_dedup_and_fallback() {
+        let mut binder = mock_binder();
+        let number = |v: &str| Expr::Value(Value::Number(v.to_string()));
+
+        // Duplicated literals are only kept once.
+        let ExprImpl::FunctionCall(func_call) = binder
+            .bind_expr(Expr::InList {
+                expr: Box::new(number("1")),
+                list: vec![number("1"), number("2"), number("1")],
+                negated: false,
+            })
+            .unwrap()
+        else {
+            panic!("expect a function call");
+        };
+        assert_eq!(func_call.inputs().len(), 3);
+
+        // Literals of a different type than the left side fall back to type alignment.
+        let ExprImpl::FunctionCall(func_call) = binder
+            .bind_expr(Expr::InList {
+                expr: Box::new(number("1")),
+                list: vec![number("1.5"), number("2")],
+                negated: false,
+            })
+            .unwrap()
+        else {
+            panic!("expect a function call");
+        };
+        assert_eq!(func_call.get_expr_type(), ExprType::In);
+        assert_eq!(func_call.inputs()[0].return_type(), DataType::Decimal);
+        assert!(matches!(func_call.inputs()[0], ExprImpl::FunctionCall(_)));
+    }
+
+    #[tokio::test]
+    async fn test_bind_row_in_list() {
+        let mut binder = mock_binder();
+        let row = |values: &[&str]| {
+            Expr::Row(
+                values
+                    .iter()
+                    .map(|v| Expr::Value(Value::Number(v.to_string())))
+                    .collect(),
+            )
+        };
+        let in_list = |list: Vec<Expr>, negated: bool| Expr::InList {
+            expr: Box::new(row(&["1", "2"])),
+            list,
+            negated,
+        };
+        let expr_type = |expr: &ExprImpl| expr.as_function_call().unwrap().get_expr_type();
+
+        // (1, 2) IN ((1, 2), (3, 4)) => ((1 = 1) AND (2 = 2)) OR ((1 = 3) AND (2 = 4))
+        let expr = binder
+            .bind_expr(in_list(vec![row(&["1", "2"]), row(&["3", "4"])], false))
+            .unwrap();
+        let or = expr.as_function_call().unwrap();
+        assert_eq!(or.get_expr_type(), ExprType::Or);
+        for and in or.inputs() {
+            assert_eq!(expr_type(and), ExprType::And);
+            let inputs = and.as_function_call().unwrap().inputs();
+            assert!(inputs.iter().all(|e| expr_type(e) == ExprType::Equal));
+        }
+
+        let expr = binder
+            .bind_expr(in_list(vec![row(&["1", "2"]), row(&["3", "4"])], true))
+            .unwrap();
+        assert_eq!(expr_type(&expr), ExprType::Not);
+
+        let err = binder
+            .bind_expr(in_list(
+                vec![row(&["1", "2"]), row(&["3", "4", "5"])],
+                false,
+            ))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Bind error: unequal number of entries in row expressions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_redundant_cast() {
+        let mut binder = mock_binder();
+        let cast = |expr: Expr, data_type: AstDataType| Expr::Cast {
+            expr: Box::new(expr),
+            data_type,
+        };
+
+        // `1::int` is already `Int32`, so no cast is added.
+        let expr = binder
+            .bind_expr(cast(
+                Expr::Value(Value::Number("1".to_string())),
+                AstDataType::Int(None),
+            ))
+            .unwrap();
+        assert!(matches!(expr, ExprImpl::Literal(_)));
+
+        // `1::int::bigint` only keeps the cast that changes the type.
+        let expr = binder
+            .bind_expr(cast(
+                cast(
+                    Expr::Value(Value::Number("1".to_string())),
+                    AstDataType::Int(None),
+                ),
+                AstDataType::BigInt(None),
+            ))
+            .unwrap();
+        let ExprImpl::FunctionCall(func_call) = expr else {
+            panic!("expect a function call");
+        };
+        assert_eq!(func_call.get_expr_type(), ExprType::Cast);
+        assert!(matches!(func_call.inputs()[0], ExprImpl::Literal(_)));
+
+        // A decimal rescale is never treated as a same-type cast. Decimals with a precision or
+        // scale are not supported yet, so it is rejected rather than elided.
+        assert!(binder
+            .bind_expr(cast(
+                Expr::Value(Value::Number("1.25".to_string())),
+                AstDataType::Decimal(Some(10), Some(1)),
+            ))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_double_colon_cast() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        for (shorthand, cast) in [
+            ("'1'::int", "CAST('1' AS int)"),
+            ("1::bigint", "CAST(1 AS bigint)"),
+            (
+                "'2022-01-01'::date::timestamp",
+                "CAST(CAST('2022-01-01' AS date) AS timestamp)",
+            ),
+        ] {
+            assert_eq!(bind(shorthand).unwrap(), bind(cast).unwrap());
+        }
+        assert_eq!(
+            bind("true::date").unwrap_err().to_string(),
+            bind("CAST(true AS date)").unwrap_err().to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_distinct_agg() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+        let distinct = |expr: ExprImpl| {
+            let ExprImpl::AggCall(agg_call) = expr else {
+                panic!("expect an aggregate call");
+            };
+            agg_call.decompose().2
+        };
+
+        let count_distinct = bind("count(DISTINCT 1)").unwrap();
+        let count = bind("count(1)").unwrap();
+        assert_ne!(count_distinct, count);
+        assert!(distinct(count_distinct));
+        assert!(!distinct(count));
+        // DISTINCT makes no difference to `max` and `min`, so it is dropped.
+        assert!(!distinct(bind("max(DISTINCT 1)").unwrap()));
+
+        assert_eq!(
+            bind("abs(DISTINCT 1)").unwrap_err().to_string(),
+            "Invalid input syntax: DISTINCT, ORDER BY or FILTER is only allowed in aggregation \
+             functions, but `abs` is not an aggregation function"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_agg_filter() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        let ExprImpl::AggCall(agg_call) = bind("sum(1) FILTER (WHERE 2 > 0)").unwrap() else {
+            panic!("expect an aggregate call");
+        };
+        let filter = agg_call.filter().as_expr_unless_true().unwrap();
+        assert_eq!(filter.return_type(), DataType::Boolean);
+        assert_eq!(
+            filter.as_function_call().unwrap().get_expr_type(),
+            ExprType::GreaterThan
+        );
+        let ExprImpl::AggCall(agg_call) = bind("sum(1)").unwrap() else {
+            panic!("expect an aggregate call");
+        };
+        assert!(agg_call.filter().always_true());
+
+        assert_eq!(
+            bind("sum(1) FILTER (WHERE 2)").unwrap_err().to_string(),
+            "Invalid input syntax: the type of filter clause should be boolean, but found Int32"
+        );
+        assert!(bind("abs(1) FILTER (WHERE true)").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_array_agg() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        // `array_agg` returns an array of its input type.
+        let ExprImpl::AggCall(agg_call) = bind("array_agg(1)").unwrap() else {
+            panic!("expect an aggregate call");
+        };
+        assert_eq!(agg_call.agg_kind(), AggKind::ArrayAgg);
+        assert_eq!(
+            agg_call.return_type(),
+            DataType::List {
+                datatype: Box::new(DataType::Int32)
+            }
+        );
+        assert!(agg_call.order_by().sort_exprs.is_empty());
+
+        // The ORDER BY inside the call is kept on the aggregate call.
+        let ordered = bind("array_agg('a' ORDER BY 2 DESC, 1.5)").unwrap();
+        let ExprImpl::AggCall(agg_call) = ordered else {
+            panic!("expect an aggregate call");
+        };
+        assert_eq!(
+            agg_call.return_type(),
+            DataType::List {
+                datatype: Box::new(DataType::Varchar)
+            }
+        );
+        let sort_exprs = &agg_call.order_by().sort_exprs;
+        assert_eq!(sort_exprs.len(), 2);
+        assert_eq!(sort_exprs[0].expr.return_type(), DataType::Int32);
+        assert_eq!(sort_exprs[0].direction, Direction::Desc);
+        assert_eq!(sort_exprs[1].expr.return_type(), DataType::Decimal);
+        assert_eq!(sort_exprs[1].direction, Direction::Asc);
+
+        assert!(bind("array_agg()").is_err());
+        assert!(bind("array_agg(1, 2)").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_hash_functions() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        let expr = bind("md5('abc')").unwrap();
+        assert_eq!(expr.return_type(), DataType::Varchar);
+        let datum = build_from_prost(&expr.to_expr_proto())
+            .unwrap()
+            .eval_row(&Row::new(vec![]))
+            .unwrap();
+        assert_eq!(
+            datum,
+            Some(ScalarImpl::Utf8(
+                "900150983cd24fb0d6963f7d28e17f72".to_string()
+            ))
+        );
+        assert!(bind("md5(1)").is_err());
+        assert!(matches!(
+            bind("sha256('abc')").unwrap_err().inner(),
+            ErrorCode::NotImplemented(..)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bind_array_length() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        for (sql, expected) in [
+            (
+                "array_length(array[1, 2, 3], 1)",
+                Some(ScalarImpl::Int32(3)),
+            ),
+            ("cardinality(array[1, 2, 3])", Some(ScalarImpl::Int32(3))),
+            (
+                "cardinality(array[array[1, 2], array[3, 4]])",
+                Some(ScalarImpl::Int32(4)),
+            ),
+            ("array_length(array[1, 2, 3], 2)", None),
+        ] {
+            let expr = bind(sql).unwrap();
+            assert_eq!(expr.return_type(), DataType::Int32);
+            let datum = build_from_prost(&expr.to_expr_proto())
+                .unwrap()
+                .eval_row(&Row::new(vec![]))
+                .unwrap();
+            assert_eq!(datum, expected, "{}", sql);
+        }
+        assert!(bind("cardinality(1)").is_err());
+        assert!(bind("array_length('abc', 1)").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_unnest() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| {
+            let stmt = Parser::parse_sql(sql).unwrap().remove(0);
+            binder.bind(stmt)
+        };
+
+        // `unnest` in the select list binds to a table function returning the element type.
+        let BoundStatement::Query(query) = bind("SELECT unnest(ARRAY[1,2,3])").unwrap() else {
+            panic!("expect a query");
+        };
+        let BoundSetExpr::Select(select) = query.body else {
+            panic!("expect a select");
+        };
+        let ExprImpl::TableFunction(table_function) = &select.select_items[0] else {
+            panic!("expect a table function");
+        };
+        assert_eq!(table_function.function_type, TableFunctionType::Unnest);
+        assert_eq!(table_function.return_type(), DataType::Int32);
+        assert_eq!(select.schema().fields()[0].data_type, DataType::Int32);
+
+        // A set of rows is not allowed where a single value is expected.
+        for sql in [
+            "SELECT 1 WHERE unnest(ARRAY[true])",
+            "SELECT 1 HAVING unnest(ARRAY[true])",
+            "SELECT sum(unnest(ARRAY[1,2,3]))",
+            "VALUES (unnest(ARRAY[1,2,3]))",
+        ] {
+            assert!(
+                matches!(
+                    bind(sql).unwrap_err().inner(),
+                    ErrorCode::InvalidInputSyntax(..)
+                ),
+                "{}",
+                sql
+            );
+        }
+        assert!(bind("SELECT unnest(1)").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_function_aliases() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql).unwrap();
+
+        for (alias, canonical, expr_type) in [
+            ("ceiling(1.5)", "ceil(1.5)", ExprType::Ceil),
+            ("pg_catalog.ceiling(1.5)", "ceil(1.5)", ExprType::Ceil),
+            ("pow(2, 10)", "power(2, 10)", ExprType::Pow),
+            (
+                "character_length('abc')",
+                "char_length('abc')",
+                ExprType::CharLength,
+            ),
+        ] {
+            let expr = bind(alias);
+            assert_eq!(
+                expr.as_function_call().unwrap().get_expr_type(),
+                expr_type,
+                "{}",
+                alias
+            );
+            assert_eq!(expr, bind(canonical));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bind_function_name_case() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        let sum = bind("sum(1)").unwrap();
+        assert!(matches!(sum, ExprImpl::AggCall(_)));
+        assert_eq!(bind("SUM(1)").unwrap(), sum);
+        assert_eq!(bind("Sum(1)").unwrap(), sum);
+        assert_eq!(bind("COUNT(*)").unwrap(), bind("count(*)").unwrap());
+        assert_eq!(bind("ABS(-1)").unwrap(), bind("abs(-1)").unwrap());
+
+        // A quoted function name is case-sensitive.
+        let not_implemented = |result: Result<ExprImpl>| match result.unwrap_err().inner() {
+            ErrorCode::NotImplemented(msg, _) => msg.clone(),
+            e => panic!("unexpected error: {}", e),
+        };
+        assert_eq!(
+            not_implemented(bind("\"Sum\"(1)")),
+            "unsupported function: \"Sum\""
+        );
+        assert_eq!(
+            not_implemented(bind("\"ABS\"(-1)")),
+            "unsupported function: \"ABS\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_named_args() {
+        let mut binder = mock_binder();
+        let mut bind = |sql: &str| bind_sql(&mut binder, sql);
+
+        let positional = bind("date_trunc('day', timestamp '2022-08-18 13:45:12')").unwrap();
+        assert_eq!(
+            bind("date_trunc(source => timestamp '2022-08-18 13:45:12', field => 'day')").unwrap(),
+            positional
+        );
+        assert_eq!(
+            bind("date_trunc('day', source => timestamp '2022-08-18 13:45:12')").unwrap(),
+            positional
+        );
+        assert_eq!(
+            bind("substr(start => 2, string => 'abc')").unwrap(),
+            bind("substr('abc', 2)").unwrap()
+        );
+
+        let bind_error = |result: Result<ExprImpl>| result.unwrap_err().to_string();
+        assert_eq!(
+            bind_error(bind("date_trunc(field => 'day', src => now())")),
+            "Bind error: function date_trunc has no parameter named \"src\""
+        );
+        assert_eq!(
+            bind_error(bind("date_trunc('day', field => 'month')")),
+            "Bind error: argument name \"field\" used more than once"
+        );
+        assert_eq!(
+            bind_error(bind("date_trunc(field => 'day', timestamp '2022-08-18')")),
+            "Bind error: positional argument cannot follow named argument"
+        );
+        assert_eq!(
+            bind_error(bind("substr(count => 1, string => 'abc')")),
+            "Bind error: missing argument \"start\" of function substr"
+        );
+        assert_eq!(
+            bind_error(bind("abs(x => 1)")),
+            "Bind error: function abs does not accept named arguments"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_cast_not_null() {
+        let mut binder = mock_binder();
+        let cast = |expr: Expr| Expr::Cast {
+            expr: Box::new(expr),
+            data_type: AstDataType::BigInt(None),
+        };
+
+        let expr = binder
+            .bind_expr(cast(Expr::Value(Value::Number("1".to_string()))))
+            .unwrap();
+        assert!(matches!(expr, ExprImpl::FunctionCall(_)));
+        assert!(expr.is_not_null());
+
+        let expr = binder
+            .bind_expr(cast(cast(Expr::Value(Value::Number("1".to_string())))))
+            .unwrap();
+        assert!(expr.is_not_null());
+
+        let expr = binder.bind_expr(cast(Expr::Value(Value::Null))).unwrap();
+        assert!(!expr.is_not_null());
+    }
+
+    #[tokio::test]
+    async fn test_bind_relative_datetime_cast() {
+        let mut binder = mock_binder();
+        let cast = |value: &str, data_type: AstDataType| Expr::Cast {
+            expr: Box::new(Expr::Value(Value::SingleQuotedString(value.to_string()))),
+            data_type,
+        };
+
+        // `'today'::date` is folded into the date of the statement time.
+        let expr = binder.bind_expr(cast("today", AstDataType::Date)).unwrap();
+        let ExprImpl::Literal(literal) = expr else {
+            panic!("expect a literal");
+        };
+        assert_eq!(
+            literal.get_data(),
+            &Some(ScalarImpl::NaiveDate(
+                str_to_date_at("today", Some(binder.statement_time)).unwrap()
+            ))
+        );
+
+        // `'now'::time` is the time of the statement time.
+        let expr = binder
+            .bind_expr(cast("now", AstDataType::Time(false)))
+            .unwrap();
+        let ExprImpl::Literal(literal) = expr else {
+            panic!("expect a literal");
+        };
+        assert_eq!(
+            literal.get_data(),
+            &Some(ScalarImpl::NaiveTime(
+                str_to_time_at("now", Some(binder.statement_time)).unwrap()
+            ))
+        );
+
+        // `'epoch'` does not depend on the statement time and is cast as usual.
+        let expr = binder
+            .bind_expr(cast("epoch", AstDataType::Timestamp(false)))
+            .unwrap();
+        assert_eq!(
+            expr.as_function_call().unwrap().get_expr_type(),
+            ExprType::Cast
+        );
+
+        assert!(binder
+            .bind_expr(Expr::TypedString {
+                data_type: AstDataType::Date,
+                value: "infinity".to_string(),
+            })
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_parameter() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (id bigint, name varchar, d date)")
+            .await
+            .unwrap();
+        let session = frontend.session_ref();
+        let param_types = |sql: &str, specified: Vec<DataType>| {
+            let stmt = Parser::parse_sql(sql).unwrap().remove(0);
+            Binder::infer_param_types(&session, stmt, specified)
+        };
+
+        // A parameter takes the type of the other side of a comparison, and is not inferred again
+        // by later occurrences.
+        assert_eq!(
+            param_types(
+                "select * from t where id = $1 and d > $3 and name = $2 and $1 > 1",
+                vec![]
+            )
+            .unwrap(),
+            vec![DataType::Int64, DataType::Varchar, DataType::Date]
+        );
+        // A specified type is kept, and a parameter without any context is `varchar`.
+        assert_eq!(
+            param_types("select $2 from t where id = $1", vec![DataType::Int32]).unwrap(),
+            vec![DataType::Int32, DataType::Varchar]
+        );
+        assert!(param_types("select * from t where id = $2", vec![]).is_err());
+
+        // Parameters are only allowed in a prepared statement.
+        let mut binder = Binder::new(&session);
+        let stmt = Parser::parse_sql("select * from t where id = $1")
+            .unwrap()
+            .remove(0);
+        assert_eq!(
+            binder.bind(stmt).unwrap_err().to_string(),
+            "Bind error: there is no parameter $1"
+        );
+
+        // A parameter can not be executed.
+        let param: ExprImpl = Parameter::new(1, ParameterTypes::new(vec![DataType::Int64])).into();
+        assert!(build_from_prost(&param.to_expr_proto()).is_err());
+    }
+
+    #[test]
+    fn test_bind_geometric_data_type() {
+        let not_implemented = |name: &str| {
+            let data_type = AstDataType::Custom(ObjectName(vec![Ident::new(name)]));
+            match bind_data_type(&data_type).unwrap_err().inner() {
+                ErrorCode::NotImplemented(msg, _) => msg.clone(),
+                e => panic!("unexpected error: {}", e),
+            }
+        };
+
+        assert_eq!(
+            not_implemented("point"),
+            "geometric type point is not supported, please store its coordinates in numeric \
+             columns instead"
+        );
+        assert_eq!(not_implemented("money"), "unsupported data type: money");
+    }
+}
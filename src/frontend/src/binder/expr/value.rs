@@ -15,11 +15,16 @@
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::{DataType, DateTimeField, Decimal, IntervalUnit, ScalarImpl};
-use risingwave_expr::vector_op::cast::str_parse;
-use risingwave_sqlparser::ast::{DateTimeField as AstDateTimeField, Expr, Value};
+use risingwave_expr::vector_op::cast::{
+    str_parse, str_to_date_at, str_to_time_at, str_to_timestamp_at, str_to_timestampz_at,
+};
+use risingwave_sqlparser::ast::{
+    DataType as AstDataType, DateTimeField as AstDateTimeField, Expr, Value,
+};
 
+use super::bind_data_type;
 use crate::binder::Binder;
-use crate::expr::{align_types, Expr as _, ExprImpl, ExprType, FunctionCall, Literal};
+use crate::expr::{align_types, Expr as _, ExprImpl, ExprType, FunctionCall, Literal, Parameter};
 
 impl Binder {
     pub fn bind_value(&mut self, value: Value) -> Result<Literal> {
@@ -46,6 +51,42 @@ impl Binder {
         Ok(Literal::new(Some(ScalarImpl::Utf8(s)), DataType::Varchar))
     }
 
+    /// Binds a typed string like `DATE '2020-01-01'`. A temporal value is parsed at bind time by
+    /// the parser of its type, so an invalid one is rejected here rather than during execution.
+    /// Relative inputs like `'today'` are resolved against the statement time. Values of other
+    /// types are bound as an explicit cast from the string.
+    pub(super) fn bind_typed_string(
+        &mut self,
+        data_type: AstDataType,
+        value: String,
+    ) -> Result<ExprImpl> {
+        let data_type = bind_data_type(&data_type)?;
+        let scalar = match data_type {
+            DataType::Date => {
+                str_to_date_at(&value, Some(self.statement_time)).map(ScalarImpl::NaiveDate)
+            }
+            DataType::Time => {
+                str_to_time_at(&value, Some(self.statement_time)).map(ScalarImpl::NaiveTime)
+            }
+            DataType::Timestamp => str_to_timestamp_at(&value, Some(self.statement_time))
+                .map(ScalarImpl::NaiveDateTime),
+            DataType::Timestampz => {
+                str_to_timestampz_at(&value, Some(self.statement_time)).map(ScalarImpl::Int64)
+            }
+            _ => {
+                let s: ExprImpl = self.bind_string(value)?.into();
+                return s.cast_explicit(data_type);
+            }
+        };
+        let scalar = scalar.map_err(|_| {
+            ErrorCode::BindError(format!(
+                "invalid input syntax for type {}: \"{}\"",
+                data_type, value
+            ))
+        })?;
+        Ok(Literal::new(Some(scalar), data_type).into())
+    }
+
     fn bind_bool(&mut self, b: bool) -> Result<Literal> {
         Ok(Literal::new(Some(ScalarImpl::Bool(b)), DataType::Boolean))
     }
@@ -140,6 +181,15 @@ impl Binder {
         let expr: ExprImpl = FunctionCall::new_unchecked(ExprType::Row, exprs, data_type).into();
         Ok(expr)
     }
+
+    /// Binds a positional parameter `$index`. Unless specified, its type is unknown until it is
+    /// inferred from the context, e.g. when cast to the type of the other side of a comparison.
+    pub(super) fn bind_parameter(&mut self, index: u64) -> Result<ExprImpl> {
+        let Some(param_types) = &self.param_types else {
+            return Err(ErrorCode::BindError(format!("there is no parameter ${}", index)).into());
+        };
+        Ok(Parameter::new(index, param_types.clone()).into())
+    }
 }
 
 #[cfg(test)]
@@ -194,6 +244,113 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_bind_cast_null() {
+        use risingwave_sqlparser::ast::{
+            BinaryOperator, DataType as AstDataType, Expr as AstExpr, Function, FunctionArg,
+            FunctionArgExpr, ObjectName,
+        };
+
+        use super::*;
+
+        let mut binder = mock_binder();
+        let expr = binder
+            .bind_expr(AstExpr::Cast {
+                expr: Box::new(AstExpr::Value(Value::Null)),
+                data_type: AstDataType::Int(None),
+            })
+            .unwrap();
+        assert_eq!(
+            expr,
+            ExprImpl::Literal(Box::new(Literal::new(None, DataType::Int32)))
+        );
+
+        // The typed null takes part in type unification with its target type.
+        let expr = binder
+            .bind_expr(AstExpr::Function(Function {
+                name: ObjectName(vec!["coalesce".into()]),
+                args: vec![
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(AstExpr::Cast {
+                        expr: Box::new(AstExpr::Value(Value::Null)),
+                        data_type: AstDataType::BigInt(None),
+                    })),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(AstExpr::Value(Value::Number(
+                        "1".into(),
+                    )))),
+                ],
+                over: None,
+                distinct: false,
+                order_by: vec![],
+                filter: None,
+            }))
+            .unwrap();
+        assert_eq!(expr.return_type(), DataType::Int64);
+
+        // A bare `NULL` has no type, so an operator over two of them is ambiguous and asks for a
+        // cast, while a typed null resolves it.
+        let add = |left: AstExpr| AstExpr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::Plus,
+            right: Box::new(AstExpr::Value(Value::Null)),
+        };
+        let err = binder
+            .bind_expr(add(AstExpr::Value(Value::Null)))
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("add explicit type casts"),
+            "{}",
+            err
+        );
+        let expr = binder
+            .bind_expr(add(AstExpr::Cast {
+                expr: Box::new(AstExpr::Value(Value::Null)),
+                data_type: AstDataType::Int(None),
+            }))
+            .unwrap();
+        assert_eq!(expr.return_type(), DataType::Int32);
+    }
+
+    #[tokio::test]
+    async fn test_bind_row() {
+        use risingwave_sqlparser::ast::Expr as AstExpr;
+
+        use super::*;
+
+        let mut binder = mock_binder();
+        let int = |v: &str| AstExpr::Value(Value::Number(v.into()));
+        let string = |v: &str| AstExpr::Value(Value::SingleQuotedString(v.into()));
+
+        let expr = binder
+            .bind_expr(AstExpr::Row(vec![int("1"), string("x")]))
+            .unwrap();
+        assert_eq!(
+            expr.return_type(),
+            DataType::new_struct(vec![DataType::Int32, DataType::Varchar], vec![])
+        );
+
+        // Nested rows are bound to nested structs.
+        let expr = binder
+            .bind_expr(AstExpr::Row(vec![
+                int("1"),
+                AstExpr::Row(vec![string("x"), int("2")]),
+            ]))
+            .unwrap();
+        assert_eq!(
+            expr.return_type(),
+            DataType::new_struct(
+                vec![
+                    DataType::Int32,
+                    DataType::new_struct(vec![DataType::Varchar, DataType::Int32], vec![]),
+                ],
+                vec![]
+            )
+        );
+
+        // An empty row is a struct without fields.
+        let expr = binder.bind_expr(AstExpr::Row(vec![])).unwrap();
+        assert_eq!(expr.return_type(), DataType::new_struct(vec![], vec![]));
+    }
+
     #[test]
     fn test_array_expr() {
         let expr: ExprImpl = FunctionCall::new_unchecked(
@@ -14,8 +14,10 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, NaiveDateTimeWrapper};
 use risingwave_sqlparser::ast::{Statement, TableAlias};
 
 mod bind_context;
@@ -41,13 +43,14 @@ pub use relation::{
     BoundWindowTableFunction, Relation, WindowTableFunctionKind,
 };
 use risingwave_common::error::ErrorCode;
-pub use select::BoundSelect;
+pub use select::{BoundDistinct, BoundSelect};
 pub use set_expr::BoundSetExpr;
 pub use statement::BoundStatement;
 pub use update::BoundUpdate;
 pub use values::BoundValues;
 
 use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::expr::ParameterTypes;
 use crate::session::{AuthContext, SessionImpl};
 
 /// `Binder` binds the identifiers in AST to columns in relations
@@ -74,10 +77,34 @@ pub struct Binder {
     next_values_id: usize,
     /// Map the cte's name to its Relation::Subquery.
     cte_to_relation: HashMap<String, (BoundQuery, TableAlias)>,
+    /// The time the statement is bound at, which constants like `'now'::timestamp` resolve to.
+    statement_time: NaiveDateTimeWrapper,
+    /// The types of the positional parameters `$1`, `$2`, ... bound so far, or `None` if the
+    /// statement is not a prepared one and can not have parameters.
+    param_types: Option<ParameterTypes>,
 }
 
 impl Binder {
     pub fn new(session: &SessionImpl) -> Binder {
+        Self::new_inner(session, None)
+    }
+
+    /// Binds a prepared statement, which can have positional parameters `$1`, `$2`, ..., only to
+    /// infer the types of the parameters. The leading parameters have the `specified` types, while
+    /// the types of the others are inferred from the context they are used in. The bound statement
+    /// is dropped, as a parameter can not be planned.
+    pub fn infer_param_types(
+        session: &SessionImpl,
+        stmt: Statement,
+        specified: Vec<DataType>,
+    ) -> Result<Vec<DataType>> {
+        let param_types = ParameterTypes::new(specified);
+        let mut binder = Self::new_inner(session, Some(param_types.clone()));
+        binder.bind(stmt)?;
+        param_types.export()
+    }
+
+    fn new_inner(session: &SessionImpl, param_types: Option<ParameterTypes>) -> Binder {
         Binder {
             catalog: session.env().catalog_reader().read_guard(),
             db_name: session.database().to_string(),
@@ -88,6 +115,8 @@ impl Binder {
             next_subquery_id: 0,
             next_values_id: 0,
             cte_to_relation: HashMap::new(),
+            statement_time: statement_time(),
+            param_types,
         }
     }
 
@@ -159,6 +188,18 @@ impl Binder {
     }
 }
 
+/// Returns the current time in UTC.
+fn statement_time() -> NaiveDateTimeWrapper {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time before Unix epoch");
+    NaiveDateTimeWrapper::with_secs_nsecs(
+        since_epoch.as_secs() as i64,
+        since_epoch.subsec_micros() * 1000,
+    )
+    .unwrap()
+}
+
 #[cfg(test)]
 pub mod test_utils {
     use super::Binder;
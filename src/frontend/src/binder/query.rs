@@ -19,7 +19,7 @@ use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{Cte, Expr, Fetch, OrderByExpr, Query, Value, With};
 
-use crate::binder::{Binder, BoundSetExpr};
+use crate::binder::{Binder, BoundDistinct, BoundSetExpr};
 use crate::expr::{CorrelatedId, Depth, ExprImpl};
 use crate::optimizer::property::{Direction, FieldOrder};
 
@@ -140,7 +140,15 @@ impl Binder {
             (Some(limit), None) => Some(parse_usize(limit)?),
             (Some(_), Some(_)) => unreachable!(), // parse error
         };
-        let offset = offset.map(parse_usize).transpose()?;
+        let offset = offset
+            .map(|offset| {
+                if offset.starts_with('-') {
+                    Err(ErrorCode::InvalidInputSyntax("OFFSET must not be negative".into()).into())
+                } else {
+                    parse_usize(offset)
+                }
+            })
+            .transpose()?;
         if let Some(with) = with {
             self.bind_with(with)?;
         }
@@ -171,7 +179,17 @@ impl Binder {
                     visible_output_num,
                 )
             })
-            .collect::<Result<_>>()?;
+            .collect::<Result<Vec<_>>>()?;
+        if let BoundSetExpr::Select(select) = &body {
+            if let BoundDistinct::DistinctOn(distinct_on) = &select.distinct {
+                Self::check_distinct_on_order(
+                    distinct_on,
+                    &select.select_items,
+                    &order,
+                    &extra_order_exprs,
+                )?;
+            }
+        }
         Ok(BoundQuery {
             body,
             order,
@@ -237,6 +255,31 @@ impl Binder {
         Ok(FieldOrder { index, direct })
     }
 
+    /// As in `PostgreSQL`, the `DISTINCT ON` expressions must match the leftmost `ORDER BY`
+    /// expressions, in any order, so that the first row of each group is well defined. The
+    /// remaining `ORDER BY` expressions then decide which row of a group is kept.
+    fn check_distinct_on_order(
+        distinct_on: &[ExprImpl],
+        select_items: &[ExprImpl],
+        order: &[FieldOrder],
+        extra_order_exprs: &[ExprImpl],
+    ) -> Result<()> {
+        for field in order.iter().take(distinct_on.len()) {
+            let expr = match select_items.get(field.index) {
+                Some(expr) => expr,
+                None => &extra_order_exprs[field.index - select_items.len()],
+            };
+            if !distinct_on.contains(expr) {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "SELECT DISTINCT ON expressions must match initial ORDER BY expressions"
+                        .to_string(),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
     fn bind_with(&mut self, with: With) -> Result<()> {
         if with.recursive {
             Err(ErrorCode::NotImplemented("recursive cte".into(), None.into()).into())
@@ -320,7 +320,8 @@ impl Binder {
                     let (schema, table_id) = self.resolve_table_id(args)?;
                     return self.bind_relation_by_id(table_id, schema, alias);
                 }
-                if let Ok(table_function_type) = TableFunctionType::from_str(func_name) {
+                let table_function_type = TableFunctionType::from_str(&name.0[0].real_value());
+                if let Ok(table_function_type) = table_function_type {
                     let args: Vec<ExprImpl> = args
                         .into_iter()
                         .map(|arg| self.bind_function_arg(arg))
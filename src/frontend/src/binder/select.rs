@@ -18,7 +18,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::{Field, Schema, PG_CATALOG_SCHEMA_NAME};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{Expr, Select, SelectItem};
+use risingwave_sqlparser::ast::{Distinct, Expr, Select, SelectItem};
 
 use super::bind_context::{Clause, ColumnBinding};
 use super::UNNAMED_COLUMN;
@@ -31,9 +31,35 @@ use crate::expr::{
     CorrelatedId, CorrelatedInputRef, Depth, Expr as _, ExprImpl, ExprType, FunctionCall, InputRef,
 };
 
+/// The `DISTINCT` quantifier of a [`BoundSelect`].
+#[derive(Debug, Clone)]
+pub enum BoundDistinct {
+    All,
+    Distinct,
+    /// `DISTINCT ON (...)` keeps the first row of each group of rows equal on these expressions,
+    /// in the order given by the `ORDER BY` of the query.
+    DistinctOn(Vec<ExprImpl>),
+}
+
+impl BoundDistinct {
+    fn exprs(&self) -> &[ExprImpl] {
+        match self {
+            BoundDistinct::DistinctOn(exprs) => exprs,
+            _ => &[],
+        }
+    }
+
+    fn exprs_mut(&mut self) -> &mut [ExprImpl] {
+        match self {
+            BoundDistinct::DistinctOn(exprs) => exprs,
+            _ => &mut [],
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BoundSelect {
-    pub distinct: bool,
+    pub distinct: BoundDistinct,
     pub select_items: Vec<ExprImpl>,
     pub aliases: Vec<Option<String>>,
     pub from: Option<Relation>,
@@ -55,6 +81,7 @@ impl BoundSelect {
             .chain(self.group_by.iter())
             .chain(self.where_clause.iter())
             .chain(self.having.iter())
+            .chain(self.distinct.exprs().iter())
     }
 
     pub fn exprs_mut(&mut self) -> impl Iterator<Item = &mut ExprImpl> {
@@ -62,6 +89,7 @@ impl BoundSelect {
             .iter_mut()
             .chain(self.group_by.iter_mut())
             .chain(self.where_clause.iter_mut())
+            .chain(self.distinct.exprs_mut().iter_mut())
         // TODO: uncomment `having` below after #4850 is fixed
         // .chain(self.having.iter_mut())
     }
@@ -105,6 +133,18 @@ impl Binder {
         // Bind SELECT clause.
         let (select_items, aliases) = self.bind_select_list(select.projection)?;
 
+        // Bind DISTINCT ON clause.
+        let distinct = match select.distinct {
+            Distinct::All => BoundDistinct::All,
+            Distinct::Distinct => BoundDistinct::Distinct,
+            Distinct::DistinctOn(exprs) => BoundDistinct::DistinctOn(
+                exprs
+                    .into_iter()
+                    .map(|expr| self.bind_expr(expr))
+                    .try_collect()?,
+            ),
+        };
+
         // Bind WHERE clause.
         self.context.clause = Some(Clause::Where);
         let selection = select
@@ -141,7 +181,7 @@ impl Binder {
             .collect::<Result<Vec<Field>>>()?;
 
         Ok(BoundSelect {
-            distinct: select.distinct,
+            distinct,
             select_items,
             aliases,
             from,
@@ -280,7 +320,7 @@ impl Binder {
         );
 
         Ok(BoundSelect {
-            distinct: false,
+            distinct: BoundDistinct::All,
             select_items,
             aliases: vec![None],
             from,
@@ -75,7 +75,7 @@ impl Binder {
         } else {
             match expr {
                 Expr::Cast { expr, data_type } => {
-                    let cast = self.bind_cast(*expr, data_type)?;
+                    let cast = self.bind_cast(*expr, data_type, None)?;
                     Ok((cast, ids))
                 }
                 _ => unreachable!(),
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::{
-    AggCall, CorrelatedInputRef, ExprImpl, FunctionCall, InputRef, Literal, Subquery,
+    AggCall, CorrelatedInputRef, ExprImpl, FunctionCall, InputRef, Literal, Parameter, Subquery,
     TableFunction, WindowFunction,
 };
 
@@ -41,6 +41,7 @@ pub trait ExprVisitor<R: Default> {
             ExprImpl::CorrelatedInputRef(inner) => self.visit_correlated_input_ref(inner),
             ExprImpl::TableFunction(inner) => self.visit_table_function(inner),
             ExprImpl::WindowFunction(inner) => self.visit_window_function(inner),
+            ExprImpl::Parameter(inner) => self.visit_parameter(inner),
         }
     }
     fn visit_function_call(&mut self, func_call: &FunctionCall) -> R {
@@ -74,6 +75,9 @@ pub trait ExprVisitor<R: Default> {
     fn visit_correlated_input_ref(&mut self, _: &CorrelatedInputRef) -> R {
         R::default()
     }
+    fn visit_parameter(&mut self, _: &Parameter) -> R {
+        R::default()
+    }
     fn visit_table_function(&mut self, func_call: &TableFunction) -> R {
         func_call
             .args
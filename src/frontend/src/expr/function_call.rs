@@ -108,11 +108,21 @@ impl FunctionCall {
 
     /// Create a cast expr over `child` to `target` type in `allows` context.
     pub fn new_cast(child: ExprImpl, target: DataType, allows: CastContext) -> Result<ExprImpl> {
-        if is_row_function(&child) {
+        // A row constructor cast to text is formatted as a whole by the struct cast below.
+        if is_row_function(&child) && target != DataType::Varchar {
             return Self::cast_nested(child, target, allows);
         }
+        // A parameter whose type is unknown takes the type it is first cast to, e.g. `id = $1`
+        // infers `$1` as the type of `id`.
+        if let ExprImpl::Parameter(parameter) = &child {
+            if !parameter.has_infer() {
+                parameter.cast_infer_type(target)?;
+                return Ok(child);
+            }
+        }
         let source = child.return_type();
         if child.is_null() {
+            // No need to cast `NULL` at runtime, e.g. `NULL::int` is just a null literal of `int`.
             Ok(Literal::new(None, target).into())
         } else if source == target {
             Ok(child)
@@ -125,6 +135,17 @@ impl FunctionCall {
                 inputs: vec![child],
             }
             .into())
+        } else if source == DataType::Interval
+            && matches!(target, DataType::Int16 | DataType::Int32)
+        {
+            // It is ambiguous which integer an interval stands for, e.g. its total minutes or
+            // seconds. Only the explicit cast to bigint is supported, yielding microseconds.
+            Err(ErrorCode::BindError(format!(
+                "cannot cast type \"{}\" to \"{}\", cast to bigint instead to get the total \
+                 number of microseconds of an interval",
+                source, target
+            ))
+            .into())
         } else {
             Err(ErrorCode::BindError(format!(
                 "cannot cast type \"{}\" to \"{}\" in {:?} context",
@@ -26,6 +26,7 @@ mod correlated_input_ref;
 mod function_call;
 mod input_ref;
 mod literal;
+mod parameter;
 mod subquery;
 mod table_function;
 mod window_function;
@@ -44,6 +45,7 @@ pub use correlated_input_ref::{CorrelatedId, CorrelatedInputRef, Depth};
 pub use function_call::{FunctionCall, FunctionCallDisplay};
 pub use input_ref::{input_ref_to_column_indices, InputRef, InputRefDisplay};
 pub use literal::Literal;
+pub use parameter::{Parameter, ParameterTypes};
 pub use subquery::{Subquery, SubqueryKind};
 pub use table_function::{TableFunction, TableFunctionType};
 pub use window_function::{WindowFunction, WindowFunctionType};
@@ -92,7 +94,8 @@ impl_expr_impl!(
     AggCall,
     Subquery,
     TableFunction,
-    WindowFunction
+    WindowFunction,
+    Parameter
 );
 
 impl ExprImpl {
@@ -143,9 +146,23 @@ impl ExprImpl {
         matches!(self, ExprImpl::Literal(literal) if literal.get_data().is_none())
     }
 
-    /// Check whether self is a literal NULL or literal string.
+    /// Check whether self is known to never evaluate to NULL. Only non-NULL literals and casts over
+    /// them are recognized for now, as a cast itself never introduces a NULL.
+    pub fn is_not_null(&self) -> bool {
+        match self {
+            ExprImpl::Literal(literal) => literal.get_data().is_some(),
+            ExprImpl::FunctionCall(func_call) if func_call.get_expr_type() == ExprType::Cast => {
+                func_call.inputs()[0].is_not_null()
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether self is a literal NULL or literal string, or a parameter whose type has not
+    /// been inferred yet.
     pub fn is_unknown(&self) -> bool {
         matches!(self, ExprImpl::Literal(literal) if literal.return_type() == DataType::Varchar)
+            || matches!(self, ExprImpl::Parameter(parameter) if !parameter.has_infer())
     }
 
     /// Shorthand to create cast expr to `target` type in implicit context.
@@ -230,7 +247,7 @@ macro_rules! impl_has_variant {
     };
 }
 
-impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery, TableFunction, WindowFunction}
+impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery, TableFunction, WindowFunction, Parameter}
 
 impl ExprImpl {
     /// This function is not meant to be called. In most cases you would want
@@ -592,6 +609,7 @@ impl Expr for ExprImpl {
             ExprImpl::CorrelatedInputRef(expr) => expr.return_type(),
             ExprImpl::TableFunction(expr) => expr.return_type(),
             ExprImpl::WindowFunction(expr) => expr.return_type(),
+            ExprImpl::Parameter(expr) => expr.return_type(),
         }
     }
 
@@ -609,6 +627,7 @@ impl Expr for ExprImpl {
             ExprImpl::WindowFunction(_e) => {
                 unreachable!("Window function should not be converted to ExprNode")
             }
+            ExprImpl::Parameter(e) => e.to_expr_proto(),
         }
     }
 }
@@ -640,6 +659,7 @@ impl std::fmt::Debug for ExprImpl {
                 }
                 Self::TableFunction(arg0) => f.debug_tuple("TableFunction").field(arg0).finish(),
                 Self::WindowFunction(arg0) => f.debug_tuple("WindowFunction").field(arg0).finish(),
+                Self::Parameter(arg0) => f.debug_tuple("Parameter").field(arg0).finish(),
             };
         }
         match self {
@@ -651,6 +671,7 @@ impl std::fmt::Debug for ExprImpl {
             Self::CorrelatedInputRef(x) => write!(f, "{:?}", x),
             Self::TableFunction(x) => write!(f, "{:?}", x),
             Self::WindowFunction(x) => write!(f, "{:?}", x),
+            Self::Parameter(x) => write!(f, "{:?}", x),
         }
     }
 }
@@ -692,6 +713,7 @@ impl std::fmt::Debug for ExprDisplay<'_> {
                 // TODO: WindowFunctionCallVerboseDisplay
                 write!(f, "{:?}", x)
             }
+            ExprImpl::Parameter(x) => write!(f, "{:?}", x),
         }
     }
 }
@@ -0,0 +1,161 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+
+use super::Expr;
+
+/// The types of the positional parameters of a statement, shared by the binder and all the
+/// [`Parameter`]s it binds. The type of a parameter is `None` until it is inferred from the
+/// context it is used in.
+#[derive(Clone, Debug)]
+pub struct ParameterTypes(Arc<RwLock<BTreeMap<u64, Option<DataType>>>>);
+
+impl ParameterTypes {
+    /// Creates the parameter types where the leading parameters have the `specified` types, e.g.
+    /// by `PREPARE name (int, text) AS ...`.
+    pub fn new(specified: Vec<DataType>) -> Self {
+        let types = specified
+            .into_iter()
+            .enumerate()
+            .map(|(i, data_type)| (i as u64 + 1, Some(data_type)))
+            .collect();
+        Self(Arc::new(RwLock::new(types)))
+    }
+
+    pub fn has_infer(&self, index: u64) -> bool {
+        self.0.read().get(&index).unwrap().is_some()
+    }
+
+    pub fn read_type(&self, index: u64) -> Option<DataType> {
+        self.0.read().get(&index).unwrap().clone()
+    }
+
+    pub fn record_new_param(&self, index: u64) {
+        self.0.write().entry(index).or_insert(None);
+    }
+
+    pub fn record_infer_type(&self, index: u64, data_type: DataType) -> Result<()> {
+        if self.has_infer(index) {
+            return Err(ErrorCode::InternalError(format!(
+                "parameter ${} has been inferred, should not be inferred again",
+                index
+            ))
+            .into());
+        }
+        self.0.write().insert(index, Some(data_type));
+        Ok(())
+    }
+
+    /// Exports the types of `$1` up to the last parameter. A parameter whose type can not be
+    /// inferred from its context is `varchar`, like an unknown-typed parameter in `PostgreSQL`.
+    pub fn export(&self) -> Result<Vec<DataType>> {
+        self.0
+            .read()
+            .iter()
+            .enumerate()
+            .map(|(i, (index, data_type))| {
+                if *index != i as u64 + 1 {
+                    return Err(ErrorCode::BindError(format!(
+                        "could not determine data type of parameter ${}",
+                        i + 1
+                    ))
+                    .into());
+                }
+                Ok(data_type.clone().unwrap_or(DataType::Varchar))
+            })
+            .collect()
+    }
+}
+
+/// A positional parameter `$index` of a prepared statement. It only exists while binding the
+/// statement to infer the parameter types, see [`crate::binder::Binder::infer_param_types`], and
+/// is never planned.
+#[derive(Clone)]
+pub struct Parameter {
+    pub index: u64,
+    param_types: ParameterTypes,
+}
+
+impl Parameter {
+    pub fn new(index: u64, param_types: ParameterTypes) -> Self {
+        param_types.record_new_param(index);
+        Self { index, param_types }
+    }
+
+    /// Whether the type of the parameter has been inferred, possibly by another occurrence of the
+    /// same parameter.
+    pub fn has_infer(&self) -> bool {
+        self.param_types.has_infer(self.index)
+    }
+
+    pub fn cast_infer_type(&self, data_type: DataType) -> Result<()> {
+        self.param_types.record_infer_type(self.index, data_type)
+    }
+}
+
+impl Expr for Parameter {
+    fn return_type(&self) -> DataType {
+        self.param_types
+            .read_type(self.index)
+            .unwrap_or(DataType::Varchar)
+    }
+
+    /// A parameter has no counterpart in the executor, as it is only bound to infer its type. It
+    /// is serialized as an unspecified expression, so that building an executor from it returns an
+    /// error rather than panicking the frontend.
+    fn to_expr_proto(&self) -> risingwave_pb::expr::ExprNode {
+        use risingwave_pb::expr::expr_node::Type;
+        use risingwave_pb::expr::ExprNode;
+        ExprNode {
+            expr_type: Type::Unspecified as i32,
+            return_type: Some(self.return_type().to_protobuf()),
+            rex_node: None,
+        }
+    }
+}
+
+impl PartialEq for Parameter {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && Arc::ptr_eq(&self.param_types.0, &other.param_types.0)
+    }
+}
+
+impl Eq for Parameter {}
+
+impl Hash for Parameter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl fmt::Debug for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Parameter")
+                .field("index", &self.index)
+                .field("type", &self.return_type())
+                .finish()
+        } else {
+            write!(f, "${}:{:?}", self.index, self.return_type())
+        }
+    }
+}
@@ -65,14 +65,11 @@ impl FromStr for TableFunctionType {
     type Err = ();
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        if s.eq_ignore_ascii_case("generate_series") {
-            Ok(TableFunctionType::Generate)
-        } else if s.eq_ignore_ascii_case("unnest") {
-            Ok(TableFunctionType::Unnest)
-        } else if s.eq_ignore_ascii_case("regexp_matches") {
-            Ok(TableFunctionType::RegexpMatches)
-        } else {
-            Err(())
+        match s {
+            "generate_series" => Ok(TableFunctionType::Generate),
+            "unnest" => Ok(TableFunctionType::Unnest),
+            "regexp_matches" => Ok(TableFunctionType::RegexpMatches),
+            _ => Err(()),
         }
     }
 }
@@ -149,7 +149,9 @@ impl From<&CastContext> for String {
 
 /// Checks whether casting from `source` to `target` is ok in `allows` context.
 pub fn cast_ok(source: &DataType, target: &DataType, allows: CastContext) -> bool {
-    cast_ok_array(source, target, allows) || cast_ok_base(source.into(), target.into(), allows)
+    cast_ok_array(source, target, allows)
+        || cast_ok_struct(source, target, allows)
+        || cast_ok_base(source.into(), target.into(), allows)
 }
 
 pub fn cast_ok_base(source: DataTypeName, target: DataTypeName, allows: CastContext) -> bool {
@@ -175,6 +177,15 @@ fn cast_ok_array(source: &DataType, target: &DataType, allows: CastContext) -> b
     }
 }
 
+/// Structs are cast from and to their composite literal text, e.g. `(1,abc)`.
+fn cast_ok_struct(source: &DataType, target: &DataType, allows: CastContext) -> bool {
+    match (source, target) {
+        (DataType::Varchar, DataType::Struct(_)) => CastContext::Explicit <= allows,
+        (DataType::Struct(_), DataType::Varchar) => CastContext::Assign <= allows,
+        _ => false,
+    }
+}
+
 pub static CAST_MAP: LazyLock<CastMap> = LazyLock::new(|| {
     use DataTypeName as T;
 
@@ -220,6 +231,15 @@ pub static CAST_MAP: LazyLock<CastMap> = LazyLock::new(|| {
     m.insert((T::Timestampz, T::Time), CastContext::Assign);
     m.insert((T::Boolean, T::Int32), CastContext::Explicit);
     m.insert((T::Int32, T::Boolean), CastContext::Explicit);
+    // Not in PG, which only casts `int4` to boolean. Other integers are cast the same way, but
+    // floating-point and numeric values are not, as it is unclear whether a tiny value is false.
+    m.insert((T::Int16, T::Boolean), CastContext::Explicit);
+    m.insert((T::Int64, T::Boolean), CastContext::Explicit);
+
+    // Not in PG. An interval is cast to and from its total number of microseconds, counting a month
+    // as 30 days.
+    m.insert((T::Interval, T::Int64), CastContext::Explicit);
+    m.insert((T::Int64, T::Interval), CastContext::Explicit);
     m
 });
 
@@ -352,9 +372,9 @@ mod tests {
             actual,
             vec![
                 "  T    T     ", // bool
-                "  TTTTTT     ",
+                "T TTTTTT     ",
                 "TT TTTTT     ",
-                " TT TTTT     ",
+                "TTT TTTT    T",
                 " TTT TTT     ",
                 " TTTT TT     ",
                 " TTTTT T     ",
@@ -363,8 +383,41 @@ mod tests {
                 "       TT TT ",
                 "       TTT T ",
                 "       T    T",
-                "       T   T ",
+                "   T   T   T ",
             ]
         );
     }
+
+    #[test]
+    fn test_cast_ok_struct() {
+        let struct_type = DataType::new_struct(
+            vec![DataType::Int32, DataType::Varchar],
+            vec!["i".to_string(), "j".to_string()],
+        );
+        assert!(cast_ok(
+            &struct_type,
+            &DataType::Varchar,
+            CastContext::Assign
+        ));
+        assert!(!cast_ok(
+            &struct_type,
+            &DataType::Varchar,
+            CastContext::Implicit
+        ));
+        assert!(cast_ok(
+            &DataType::Varchar,
+            &struct_type,
+            CastContext::Explicit
+        ));
+        assert!(!cast_ok(
+            &DataType::Varchar,
+            &struct_type,
+            CastContext::Assign
+        ));
+        assert!(!cast_ok(
+            &struct_type,
+            &DataType::Int32,
+            CastContext::Explicit
+        ));
+    }
 }
@@ -135,6 +135,21 @@ fn infer_type_for_special(
                 .try_collect()?;
             Ok(Some(DataType::Varchar))
         }
+        ExprType::Format => {
+            ensure_arity!("format", 1 <= | inputs |);
+            let inputs_owned = std::mem::take(inputs);
+            *inputs = inputs_owned
+                .into_iter()
+                .enumerate()
+                .map(|(i, input)| match i {
+                    // 0-th arg is the format string
+                    0 => input.cast_implicit(DataType::Varchar),
+                    // subsequent can be any type, using the output format
+                    _ => input.cast_output(),
+                })
+                .try_collect()?;
+            Ok(Some(DataType::Varchar))
+        }
         ExprType::ConcatOp => {
             let inputs_owned = std::mem::take(inputs);
             *inputs = inputs_owned
@@ -170,6 +185,15 @@ fn infer_type_for_special(
                 datatype: Box::new(DataType::Varchar),
             }))
         }
+        ExprType::RegexpReplace => {
+            ensure_arity!("regexp_replace", 3 <= | inputs | <= 4);
+            let inputs_owned = std::mem::take(inputs);
+            *inputs = inputs_owned
+                .into_iter()
+                .map(|input| input.cast_implicit(DataType::Varchar))
+                .try_collect()?;
+            Ok(Some(DataType::Varchar))
+        }
         ExprType::ArrayCat => {
             ensure_arity!("array_cat", | inputs | == 2);
             let left_type = inputs[0].return_type();
@@ -233,6 +257,43 @@ fn infer_type_for_special(
                 .into()),
             }
         }
+        ExprType::StringToArray => {
+            ensure_arity!("string_to_array", 2 <= | inputs | <= 3);
+            let inputs_owned = std::mem::take(inputs);
+            *inputs = inputs_owned
+                .into_iter()
+                .map(|input| input.cast_implicit(DataType::Varchar))
+                .try_collect()?;
+            Ok(Some(DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            }))
+        }
+        ExprType::ArrayToString => {
+            ensure_arity!("array_to_string", 2 <= | inputs | <= 3);
+            ensure_array_arg("array_to_string", &inputs[0])?;
+            let inputs_owned = std::mem::take(inputs);
+            *inputs = inputs_owned
+                .into_iter()
+                .enumerate()
+                .map(|(i, input)| match i {
+                    0 => Ok(input),
+                    _ => input.cast_implicit(DataType::Varchar),
+                })
+                .try_collect()?;
+            Ok(Some(DataType::Varchar))
+        }
+        ExprType::ArrayLength => {
+            ensure_arity!("array_length", | inputs | == 2);
+            ensure_array_arg("array_length", &inputs[0])?;
+            let dim = inputs.pop().unwrap();
+            inputs.push(dim.cast_implicit(DataType::Int32)?);
+            Ok(Some(DataType::Int32))
+        }
+        ExprType::Cardinality => {
+            ensure_arity!("cardinality", | inputs | == 1);
+            ensure_array_arg("cardinality", &inputs[0])?;
+            Ok(Some(DataType::Int32))
+        }
         ExprType::Vnode => {
             ensure_arity!("vnode", 1 <= | inputs |);
             Ok(Some(DataType::Int16))
@@ -241,6 +302,18 @@ fn infer_type_for_special(
     }
 }
 
+fn ensure_array_arg(func: &str, input: &ExprImpl) -> Result<()> {
+    if !matches!(input.return_type(), DataType::List { .. }) {
+        return Err(ErrorCode::BindError(format!(
+            "{} expects an array, but got {}",
+            func,
+            input.return_type()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
 /// From all available functions in `sig_map`, find and return the best matching `FuncSign` for the
 /// provided `func_type` and `inputs`. This not only support exact function signature match, but can
 /// also match `substr(varchar, smallint)` or even `substr(varchar, unknown)` to `substr(varchar,
@@ -688,6 +761,12 @@ fn build_type_derive_map() -> FuncSigMap {
         &[T::Int16, T::Int32, T::Int64, T::Decimal],
     );
     map.insert(E::RoundDigit, vec![T::Decimal, T::Int32], T::Decimal);
+    map.insert(E::Pow, vec![T::Float64, T::Float64], T::Float64);
+    map.insert(E::Sqrt, vec![T::Float64], T::Float64);
+    for e in [E::Sin, E::Cos, E::Tan, E::Asin, E::Acos, E::Atan] {
+        map.insert(e, vec![T::Float64], T::Float64);
+    }
+    map.insert(E::Atan2, vec![T::Float64, T::Float64], T::Float64);
 
     // build bitwise operator
     // bitwise operator
@@ -698,6 +777,7 @@ fn build_type_derive_map() -> FuncSigMap {
         &[E::BitwiseAnd, E::BitwiseOr, E::BitwiseXor],
         &integral_types,
     );
+    build_binary_atm_funcs(&mut map, &[E::Gcd, E::Lcm], &integral_types);
 
     // Shift Operator is not using `build_binary_atm_funcs` because
     // allowed rhs is different from allowed lhs
@@ -711,6 +791,7 @@ fn build_type_derive_map() -> FuncSigMap {
     }
 
     build_unary_atm_funcs(&mut map, &[E::BitwiseNot], &[T::Int16, T::Int32, T::Int64]);
+    map.insert(E::Factorial, vec![T::Int64], T::Int64);
 
     build_round_funcs(&mut map, E::Round);
     build_round_funcs(&mut map, E::Ceil);
@@ -747,15 +828,26 @@ fn build_type_derive_map() -> FuncSigMap {
     for t in [T::Timestamp, T::Date] {
         map.insert(E::TumbleStart, vec![t, T::Interval], T::Timestamp);
     }
+    for t in [T::Timestamp, T::Interval] {
+        map.insert(E::DateTrunc, vec![T::Varchar, t], t);
+    }
 
     // string expressions
-    for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper, E::Md5] {
+    for e in [
+        E::Trim,
+        E::Ltrim,
+        E::Rtrim,
+        E::Lower,
+        E::Upper,
+        E::Md5,
+        E::Reverse,
+    ] {
         map.insert(e, vec![T::Varchar], T::Varchar);
     }
     for e in [E::Trim, E::Ltrim, E::Rtrim] {
         map.insert(e, vec![T::Varchar, T::Varchar], T::Varchar);
     }
-    for e in [E::Repeat, E::Substr] {
+    for e in [E::Repeat, E::Substr, E::Left, E::Right] {
         map.insert(e, vec![T::Varchar, T::Int32], T::Varchar);
     }
     map.insert(E::Substr, vec![T::Varchar, T::Int32, T::Int32], T::Varchar);
@@ -781,8 +873,10 @@ fn build_type_derive_map() -> FuncSigMap {
     ] {
         map.insert(e, vec![T::Varchar], T::Int32);
     }
+    map.insert(E::Chr, vec![T::Int32], T::Varchar);
     map.insert(E::Position, vec![T::Varchar, T::Varchar], T::Int32);
     map.insert(E::Like, vec![T::Varchar, T::Varchar], T::Boolean);
+    map.insert(E::StartsWith, vec![T::Varchar, T::Varchar], T::Boolean);
     map.insert(
         E::SplitPart,
         vec![T::Varchar, T::Varchar, T::Int32],
@@ -790,6 +884,8 @@ fn build_type_derive_map() -> FuncSigMap {
     );
     // TODO: Support more `to_char` types.
     map.insert(E::ToChar, vec![T::Timestamp, T::Varchar], T::Varchar);
+    map.insert(E::ToDate, vec![T::Varchar, T::Varchar], T::Date);
+    map.insert(E::ToTimestamp, vec![T::Varchar, T::Varchar], T::Timestamp);
 
     map
 }
@@ -894,6 +990,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_temporal_arithmetics() {
+        use DataType::*;
+        let cases = vec![
+            (ExprType::Add, Date, Interval, Timestamp),
+            (ExprType::Add, Interval, Date, Timestamp),
+            (ExprType::Subtract, Date, Interval, Timestamp),
+            (ExprType::Add, Date, Int32, Date),
+            (ExprType::Subtract, Date, Date, Int32),
+            (ExprType::Add, Timestamp, Interval, Timestamp),
+            (ExprType::Subtract, Timestamp, Interval, Timestamp),
+            (ExprType::Subtract, Timestamp, Timestamp, Interval),
+            (ExprType::Add, Date, Time, Timestamp),
+            (ExprType::Add, Interval, Interval, Interval),
+            (ExprType::Subtract, Interval, Interval, Interval),
+            (ExprType::Multiply, Interval, Float64, Interval),
+            (ExprType::Divide, Interval, Int32, Interval),
+        ];
+        for (expr, t1, t2, tr) in cases {
+            test_simple_infer_type(expr, vec![t1, t2], tr);
+        }
+
+        test_infer_type_not_exist(ExprType::Multiply, vec![Date, Interval]);
+        test_infer_type_not_exist(ExprType::Subtract, vec![Interval, Date]);
+        test_infer_type_not_exist(ExprType::Add, vec![Timestamp, Timestamp]);
+        test_infer_type_not_exist(ExprType::Divide, vec![Int32, Interval]);
+    }
+
     #[test]
     fn test_bitwise() {
         use DataType::*;
@@ -901,6 +1025,8 @@ mod tests {
             ExprType::BitwiseAnd,
             ExprType::BitwiseOr,
             ExprType::BitwiseXor,
+            ExprType::Gcd,
+            ExprType::Lcm,
         ];
         let num_promote_table = vec![
             (Int16, Int16, Int16),
@@ -931,6 +1057,43 @@ mod tests {
             test_simple_infer_type(expr, vec![t1, t2], tr);
         }
     }
+
+    #[test]
+    fn test_trigonometric() {
+        use DataType::*;
+        let num_types = [Int16, Int32, Int64, Decimal, Float32, Float64];
+        let unary_exprs = [
+            ExprType::Sin,
+            ExprType::Cos,
+            ExprType::Tan,
+            ExprType::Asin,
+            ExprType::Acos,
+            ExprType::Atan,
+        ];
+        for (expr, t) in iproduct!(unary_exprs, num_types.clone()) {
+            test_simple_infer_type(expr, vec![t.clone()], Float64);
+            test_infer_type_not_exist(expr, vec![t.clone(), t]);
+        }
+        for (t1, t2) in iproduct!(num_types.clone(), num_types.clone()) {
+            test_simple_infer_type(ExprType::Atan2, vec![t1, t2], Float64);
+        }
+        for t in num_types {
+            test_infer_type_not_exist(ExprType::Atan2, vec![t]);
+        }
+        test_infer_type_not_exist(ExprType::Sin, vec![Boolean]);
+    }
+
+    #[test]
+    fn test_round() {
+        use DataType::*;
+        for expr in [ExprType::Round, ExprType::Ceil, ExprType::Floor] {
+            test_simple_infer_type(expr, vec![Decimal], Decimal);
+            test_simple_infer_type(expr, vec![Float32], Float64);
+            test_simple_infer_type(expr, vec![Float64], Float64);
+            test_infer_type_not_exist(expr, vec![Boolean]);
+        }
+    }
+
     #[test]
     fn test_bool_num_not_exist() {
         let exprs = vec![
@@ -130,6 +130,9 @@ impl ExprRewriter for BooleanConstantFolding {
                 if input.is_null() {
                     return ExprImpl::literal_bool(true);
                 }
+                if input.is_not_null() {
+                    return ExprImpl::literal_bool(false);
+                }
             }
             Type::IsNotTrue => {
                 let input = inputs.first().unwrap();
@@ -151,8 +154,11 @@ impl ExprRewriter for BooleanConstantFolding {
             }
             Type::IsNotNull => {
                 let input = inputs.first().unwrap();
-                if let ExprImpl::Literal(lit) = input {
-                    return ExprImpl::literal_bool(lit.get_data().is_some());
+                if input.is_null() {
+                    return ExprImpl::literal_bool(false);
+                }
+                if input.is_not_null() {
+                    return ExprImpl::literal_bool(true);
                 }
             }
             // binary functions
@@ -469,6 +475,42 @@ mod tests {
         assert_eq!(res.index(), 0);
     }
 
+    #[test]
+    fn constant_boolean_folding_is_null() {
+        // expr := CAST(1 AS bigint) IS NOT NULL
+        let cast = ExprImpl::literal_int(1)
+            .cast_explicit(DataType::Int64)
+            .unwrap();
+        assert!(cast.as_function_call().is_some());
+        let expr: ExprImpl = FunctionCall::new(Type::IsNotNull, vec![cast.clone()])
+            .unwrap()
+            .into();
+
+        let res = fold_boolean_constant(expr);
+        assert_eq!(
+            *res.as_literal().unwrap().get_data(),
+            Some(ScalarImpl::Bool(true))
+        );
+
+        // expr := CAST(1 AS bigint) IS NULL
+        let expr: ExprImpl = FunctionCall::new(Type::IsNull, vec![cast]).unwrap().into();
+
+        let res = fold_boolean_constant(expr);
+        assert_eq!(
+            *res.as_literal().unwrap().get_data(),
+            Some(ScalarImpl::Bool(false))
+        );
+
+        // expr := A IS NULL
+        let expr: ExprImpl =
+            FunctionCall::new(Type::IsNull, vec![InputRef::new(0, DataType::Int64).into()])
+                .unwrap()
+                .into();
+
+        let res = fold_boolean_constant(expr);
+        assert!(res.as_function_call().is_some());
+    }
+
     #[test]
     fn constant_boolean_folding_complex() {
         // expr := (false && true) && (true || 1 == 2)
@@ -59,12 +59,12 @@ impl FromStr for WindowFunctionType {
     type Err = ErrorCode;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s.to_ascii_lowercase().as_str() {
+        match s {
             "row_number" => Ok(WindowFunctionType::RowNumber),
             "rank" => Ok(WindowFunctionType::Rank),
             "dense_rank" => Ok(WindowFunctionType::DenseRank),
             _ => Err(ErrorCode::NotImplemented(
-                format!("unknown table function kind: {s}"),
+                format!("{s} as over window function"),
                 None.into(),
             )),
         }
@@ -839,7 +839,8 @@ impl LogicalAggBuilder {
 
         self.is_in_filter_clause = true;
         // filter expr is not added to `input_proj_builder` as a whole. Special exprs incl
-        // subquery/agg/table are rejected in `bind_agg`.
+        // agg/table are rejected in `bind_agg`, and subqueries have been planned below the agg
+        // by `Planner::plan_select`.
         let filter = filter.rewrite_expr(self);
         self.is_in_filter_clause = false;
 
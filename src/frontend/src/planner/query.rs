@@ -37,8 +37,8 @@ impl Planner {
         } = query;
 
         let extra_order_exprs_len = extra_order_exprs.len();
-        let mut plan = self.plan_set_expr(body, extra_order_exprs)?;
         let order = Order { field_order: order };
+        let mut plan = self.plan_set_expr(body, extra_order_exprs, &order)?;
         if limit.is_some() || offset.is_some() {
             let limit = limit.unwrap_or(LIMIT_ALL_COUNT);
             let offset = offset.unwrap_or_default();
@@ -18,16 +18,17 @@ use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_pb::plan_common::JoinType;
 
-use crate::binder::BoundSelect;
+use crate::binder::{BoundDistinct, BoundSelect};
 use crate::expr::{
-    CorrelatedId, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Subquery,
-    SubqueryKind,
+    AggCall, CorrelatedId, Expr, ExprImpl, ExprRewriter, ExprType, ExprVisitor, FunctionCall,
+    InputRef, Subquery, SubqueryKind,
 };
 pub use crate::optimizer::plan_node::LogicalFilter;
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalApply, LogicalOverAgg, LogicalProject, LogicalProjectSet, LogicalValues,
-    PlanAggCall, PlanRef,
+    LogicalAgg, LogicalApply, LogicalOverAgg, LogicalProject, LogicalProjectSet, LogicalTopN,
+    LogicalValues, PlanAggCall, PlanRef,
 };
+use crate::optimizer::property::Order;
 use crate::planner::Planner;
 use crate::utils::Condition;
 impl Planner {
@@ -43,9 +44,10 @@ impl Planner {
             ..
         }: BoundSelect,
         extra_order_exprs: Vec<ExprImpl>,
+        order: &Order,
     ) -> Result<PlanRef> {
         // Append expressions in ORDER BY.
-        if distinct && !extra_order_exprs.is_empty() {
+        if matches!(distinct, BoundDistinct::Distinct) && !extra_order_exprs.is_empty() {
             return Err(ErrorCode::InvalidInputSyntax(
                 "for SELECT DISTINCT, ORDER BY expressions must appear in select list".into(),
             )
@@ -53,6 +55,29 @@ impl Planner {
         }
         select_items.extend(extra_order_exprs);
 
+        // DISTINCT ON keeps the first row of each group in the ORDER BY order, so it is planned as
+        // a group Top-1 over the output columns of its expressions.
+        let distinct_on_key: Option<Vec<usize>> = match &distinct {
+            BoundDistinct::DistinctOn(exprs) => Some(
+                exprs
+                    .iter()
+                    .map(|expr| {
+                        select_items
+                            .iter()
+                            .position(|item| item == expr)
+                            .ok_or_else(|| {
+                                ErrorCode::NotImplemented(
+                                    "DISTINCT ON expressions not in the select list or ORDER BY"
+                                        .to_string(),
+                                    None.into(),
+                                )
+                            })
+                    })
+                    .try_collect()?,
+            ),
+            _ => None,
+        };
+
         // Plan the FROM clause.
         let mut root = match from {
             None => self.create_dummy_values(),
@@ -63,6 +88,20 @@ impl Planner {
             root = self.plan_where(root, where_clause)?;
         }
         // Plan the SELECT clause.
+        // Subqueries in the FILTER clause of aggregates are evaluated on each input row of the
+        // aggregation, so they are planned below it.
+        if select_items
+            .iter()
+            .chain(having.iter())
+            .any(has_subquery_in_agg_filter)
+        {
+            let has_having = having.is_some();
+            let exprs = select_items.into_iter().chain(having).collect();
+            let (new_root, mut exprs) = self.substitute_subqueries_impl(root, exprs, true)?;
+            root = new_root;
+            having = if has_having { exprs.pop() } else { None };
+            select_items = exprs;
+        }
         // TODO: select-agg, group-by, having can also contain subquery exprs.
         let has_agg_call = select_items.iter().any(|expr| expr.has_agg_call());
         if !group_by.is_empty() || having.is_some() || has_agg_call {
@@ -87,9 +126,23 @@ impl Planner {
             root = LogicalProject::create(root, select_items);
         }
 
-        if distinct {
-            let group_key = (0..root.schema().fields().len()).collect();
-            root = LogicalAgg::new(vec![], group_key, root).into();
+        match distinct {
+            BoundDistinct::All => {}
+            BoundDistinct::Distinct => {
+                let group_key = (0..root.schema().fields().len()).collect();
+                root = LogicalAgg::new(vec![], group_key, root).into();
+            }
+            BoundDistinct::DistinctOn(_) => {
+                root = LogicalTopN::with_group(
+                    root,
+                    1,
+                    0,
+                    false,
+                    order.clone(),
+                    distinct_on_key.unwrap(),
+                )
+                .into();
+            }
         }
 
         Ok(root)
@@ -221,20 +274,51 @@ impl Planner {
     /// The [`InputRef`]s' indexes start from `root.schema().len()`,
     /// which means they are additional columns beyond the original `root`.
     fn substitute_subqueries(
+        &mut self,
+        root: PlanRef,
+        exprs: Vec<ExprImpl>,
+    ) -> Result<(PlanRef, Vec<ExprImpl>)> {
+        self.substitute_subqueries_impl(root, exprs, false)
+    }
+
+    /// If `agg_filter_only` is true, only subqueries in the FILTER clause of aggregate calls are
+    /// substituted.
+    fn substitute_subqueries_impl(
         &mut self,
         mut root: PlanRef,
         mut exprs: Vec<ExprImpl>,
+        agg_filter_only: bool,
     ) -> Result<(PlanRef, Vec<ExprImpl>)> {
         struct SubstituteSubQueries {
             input_col_num: usize,
             subqueries: Vec<Subquery>,
             correlated_indices_collection: Vec<Vec<usize>>,
             correlated_id: CorrelatedId,
+            agg_filter_only: bool,
+            in_agg_filter: bool,
         }
 
         // TODO: consider the multi-subquery case for normal predicate.
         impl ExprRewriter for SubstituteSubQueries {
+            fn rewrite_agg_call(&mut self, agg_call: AggCall) -> ExprImpl {
+                let (func_type, inputs, distinct, order_by, filter) = agg_call.decompose();
+                let inputs = inputs
+                    .into_iter()
+                    .map(|expr| self.rewrite_expr(expr))
+                    .collect();
+                let order_by = order_by.rewrite_expr(self);
+                self.in_agg_filter = true;
+                let filter = filter.rewrite_expr(self);
+                self.in_agg_filter = false;
+                AggCall::new(func_type, inputs, distinct, order_by, filter)
+                    .unwrap()
+                    .into()
+            }
+
             fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+                if self.agg_filter_only && !self.in_agg_filter {
+                    return subquery.into();
+                }
                 let input_ref = InputRef::new(self.input_col_num, subquery.return_type()).into();
                 self.input_col_num += 1;
                 self.correlated_indices_collection.push(
@@ -252,6 +336,8 @@ impl Planner {
             subqueries: vec![],
             correlated_indices_collection: vec![],
             correlated_id,
+            agg_filter_only,
+            in_agg_filter: false,
         };
         exprs = exprs
             .into_iter()
@@ -312,3 +398,32 @@ impl Planner {
         )
     }
 }
+
+/// Whether `expr` has a subquery in the FILTER clause of an aggregate call.
+fn has_subquery_in_agg_filter(expr: &ExprImpl) -> bool {
+    struct Has {
+        in_agg_filter: bool,
+    }
+
+    impl ExprVisitor<bool> for Has {
+        fn merge(a: bool, b: bool) -> bool {
+            a | b
+        }
+
+        fn visit_agg_call(&mut self, agg_call: &AggCall) -> bool {
+            self.in_agg_filter = true;
+            let has = agg_call.filter().visit_expr(self);
+            self.in_agg_filter = false;
+            has
+        }
+
+        fn visit_subquery(&mut self, _: &Subquery) -> bool {
+            self.in_agg_filter
+        }
+    }
+
+    let mut visitor = Has {
+        in_agg_filter: false,
+    };
+    visitor.visit_expr(expr)
+}
@@ -17,6 +17,7 @@ use risingwave_common::error::Result;
 use crate::binder::BoundSetExpr;
 use crate::expr::ExprImpl;
 use crate::optimizer::plan_node::PlanRef;
+use crate::optimizer::property::Order;
 use crate::planner::Planner;
 
 impl Planner {
@@ -24,9 +25,10 @@ impl Planner {
         &mut self,
         set_expr: BoundSetExpr,
         extra_order_exprs: Vec<ExprImpl>,
+        order: &Order,
     ) -> Result<PlanRef> {
         match set_expr {
-            BoundSetExpr::Select(s) => self.plan_select(*s, extra_order_exprs),
+            BoundSetExpr::Select(s) => self.plan_select(*s, extra_order_exprs, order),
             BoundSetExpr::Values(v) => self.plan_values(*v),
         }
     }
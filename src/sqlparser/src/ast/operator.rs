@@ -76,6 +76,8 @@ pub enum BinaryOperator {
     NotLike,
     ILike,
     NotILike,
+    SimilarTo,
+    NotSimilarTo,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -111,6 +113,8 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotLike => "NOT LIKE",
             BinaryOperator::ILike => "ILIKE",
             BinaryOperator::NotILike => "NOT ILIKE",
+            BinaryOperator::SimilarTo => "SIMILAR TO",
+            BinaryOperator::NotSimilarTo => "NOT SIMILAR TO",
             BinaryOperator::BitwiseOr => "|",
             BinaryOperator::BitwiseAnd => "&",
             BinaryOperator::BitwiseXor => "^",
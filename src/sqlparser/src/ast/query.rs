@@ -129,7 +129,7 @@ impl fmt::Display for SetOperator {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Select {
-    pub distinct: bool,
+    pub distinct: Distinct,
     /// projection expressions
     pub projection: Vec<SelectItem>,
     /// FROM
@@ -146,7 +146,7 @@ pub struct Select {
 
 impl fmt::Display for Select {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "SELECT{}", if self.distinct { " DISTINCT" } else { "" })?;
+        write!(f, "SELECT{}", self.distinct)?;
         write!(f, " {}", display_comma_separated(&self.projection))?;
         if !self.from.is_empty() {
             write!(f, " FROM {}", display_comma_separated(&self.from))?;
@@ -169,6 +169,30 @@ impl fmt::Display for Select {
     }
 }
 
+/// The `DISTINCT` quantifier of a `SELECT`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Distinct {
+    /// `SELECT` or `SELECT ALL`
+    All,
+    /// `SELECT DISTINCT`
+    Distinct,
+    /// `SELECT DISTINCT ON (expr, ...)`
+    DistinctOn(Vec<Expr>),
+}
+
+impl fmt::Display for Distinct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distinct::All => Ok(()),
+            Distinct::Distinct => write!(f, " DISTINCT"),
+            Distinct::DistinctOn(exprs) => {
+                write!(f, " DISTINCT ON ({})", display_comma_separated(exprs))
+            }
+        }
+    }
+}
+
 /// A hive LATERAL VIEW with potential column aliases
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
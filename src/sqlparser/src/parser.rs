@@ -475,6 +475,10 @@ impl Parser {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
+            Token::Parameter(s) => match s.parse::<u64>() {
+                Ok(index) if index > 0 => Ok(Expr::Parameter { index }),
+                _ => parser_err!(format!("invalid parameter ${}", s)),
+            },
 
             Token::LParen => {
                 let expr =
@@ -758,10 +762,24 @@ impl Parser {
         let expr = self.parse_expr()?;
         self.expect_keyword(Keyword::AS)?;
         let data_type = self.parse_data_type()?;
+        let collation = if self.parse_keyword(Keyword::COLLATE) {
+            Some(self.parse_object_name()?)
+        } else {
+            None
+        };
         self.expect_token(&Token::RParen)?;
-        Ok(Expr::Cast {
+        let cast = Expr::Cast {
             expr: Box::new(expr),
             data_type,
+        };
+        // `CAST(expr AS type COLLATE collation)` is equivalent to
+        // `CAST(expr AS type) COLLATE collation`.
+        Ok(match collation {
+            Some(collation) => Expr::Collate {
+                expr: Box::new(cast),
+                collation,
+            },
+            None => cast,
         })
     }
 
@@ -1015,11 +1033,17 @@ impl Parser {
                 Keyword::OR => Some(BinaryOperator::Or),
                 Keyword::LIKE => Some(BinaryOperator::Like),
                 Keyword::ILIKE => Some(BinaryOperator::ILike),
+                Keyword::SIMILAR => {
+                    self.expect_keyword(Keyword::TO)?;
+                    Some(BinaryOperator::SimilarTo)
+                }
                 Keyword::NOT => {
                     if self.parse_keyword(Keyword::LIKE) {
                         Some(BinaryOperator::NotLike)
                     } else if self.parse_keyword(Keyword::ILIKE) {
                         Some(BinaryOperator::NotILike)
+                    } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
+                        Some(BinaryOperator::NotSimilarTo)
                     } else {
                         None
                     }
@@ -1031,10 +1055,23 @@ impl Parser {
         };
 
         if let Some(op) = regular_binary_operator {
+            let right =
+                match self.parse_one_of_keywords(&[Keyword::ANY, Keyword::SOME, Keyword::ALL]) {
+                    Some(keyword) => {
+                        self.expect_token(&Token::LParen)?;
+                        let sub = Box::new(self.parse_expr()?);
+                        self.expect_token(&Token::RParen)?;
+                        match keyword {
+                            Keyword::ALL => Expr::AllOp(sub),
+                            _ => Expr::SomeOp(sub),
+                        }
+                    }
+                    None => self.parse_subexpr(precedence)?,
+                };
             Ok(Expr::BinaryOp {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(right),
             })
         } else if let Token::Word(w) = &tok {
             match w.keyword {
@@ -1175,6 +1212,7 @@ impl Parser {
                 Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
                 _ => Ok(0),
             },
             Token::Word(w) if w.keyword == Keyword::IS => Ok(17),
@@ -1182,6 +1220,7 @@ impl Parser {
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
             Token::Eq
             | Token::Lt
             | Token::LtEq
@@ -1393,6 +1432,21 @@ impl Parser {
         }
     }
 
+    /// Parse the quantifier of a `SELECT`, i.e. `ALL`, `DISTINCT` or `DISTINCT ON (expr, ...)`.
+    pub fn parse_select_distinct(&mut self) -> Result<Distinct, ParserError> {
+        if !self.parse_all_or_distinct()? {
+            return Ok(Distinct::All);
+        }
+        if self.parse_keyword(Keyword::ON) {
+            self.expect_token(&Token::LParen)?;
+            let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(Distinct::DistinctOn(exprs))
+        } else {
+            Ok(Distinct::Distinct)
+        }
+    }
+
     /// Parse either `ALL` or `DISTINCT`. Returns `true` if `DISTINCT` is parsed and results in a
     /// `ParserError` if both `ALL` and `DISTINCT` are fround.
     pub fn parse_all_or_distinct(&mut self) -> Result<bool, ParserError> {
@@ -2573,7 +2627,7 @@ impl Parser {
     /// Parse a restricted `SELECT` statement (no CTEs / `UNION` / `ORDER BY`),
     /// assuming the initial `SELECT` was already consumed
     pub fn parse_select(&mut self) -> Result<Select, ParserError> {
-        let distinct = self.parse_all_or_distinct()?;
+        let distinct = self.parse_select_distinct()?;
 
         let projection = self.parse_comma_separated(Parser::parse_select_item)?;
 
@@ -3247,8 +3301,15 @@ impl Parser {
     }
 
     /// Parse an OFFSET clause
+    ///
+    /// A negative offset is accepted here and rejected when binding, so that the error is reported
+    /// like in `PostgreSQL`.
     pub fn parse_offset(&mut self) -> Result<String, ParserError> {
-        let value = self.parse_number_value()?;
+        let negative = self.consume_token(&Token::Minus);
+        let mut value = self.parse_number_value()?;
+        if negative {
+            value.insert(0, '-');
+        }
         _ = self.parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS]);
         Ok(value)
     }
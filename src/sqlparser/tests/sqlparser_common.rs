@@ -247,7 +247,7 @@ fn parse_top_level() {
 fn parse_simple_select() {
     let sql = "SELECT id, fname, lname FROM customer WHERE id = 1 LIMIT 5";
     let select = verified_only_select(sql);
-    assert!(!select.distinct);
+    assert_eq!(select.distinct, Distinct::All);
     assert_eq!(3, select.projection.len());
     let select = verified_query(sql);
     assert_eq!(Some("5".to_string()), select.limit);
@@ -267,13 +267,33 @@ fn parse_limit_is_not_an_alias() {
 fn parse_select_distinct() {
     let sql = "SELECT DISTINCT name FROM customer";
     let select = verified_only_select(sql);
-    assert!(select.distinct);
+    assert_eq!(select.distinct, Distinct::Distinct);
     assert_eq!(
         &SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("name"))),
         only(&select.projection)
     );
 }
 
+#[test]
+fn parse_select_distinct_on() {
+    let sql = "SELECT DISTINCT ON (a, b + 1) a, c FROM t ORDER BY a, b + 1, c DESC";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        select.distinct,
+        Distinct::DistinctOn(vec![
+            Expr::Identifier(Ident::new("a")),
+            Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("b"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            },
+        ])
+    );
+
+    let result = parse_sql_statements("SELECT DISTINCT ON a FROM t");
+    assert!(result.is_err());
+}
+
 #[test]
 fn parse_select_all() {
     one_statement_parses_to("SELECT ALL name FROM customer", "SELECT name FROM customer");
@@ -714,6 +734,50 @@ fn parse_like() {
     chk(true);
 }
 
+#[test]
+fn parse_similar_to() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%(a|b)'",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("name"))),
+                op: if negated {
+                    BinaryOperator::NotSimilarTo
+                } else {
+                    BinaryOperator::SimilarTo
+                },
+                right: Box::new(Expr::Value(Value::SingleQuotedString("%(a|b)".to_string()))),
+            },
+            select.selection.unwrap()
+        );
+
+        // SIMILAR TO has the same precedence as LIKE.
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%a' IS NULL",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::IsNull(Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("name"))),
+                op: if negated {
+                    BinaryOperator::NotSimilarTo
+                } else {
+                    BinaryOperator::SimilarTo
+                },
+                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            })),
+            select.selection.unwrap()
+        );
+    }
+    chk(false);
+    chk(true);
+}
+
 #[test]
 fn parse_ilike() {
     fn chk(negated: bool) {
@@ -1112,6 +1176,12 @@ fn parse_limit_accepts_all() {
         "SELECT id, fname, lname FROM customer WHERE id = 1 LIMIT ALL",
         "SELECT id, fname, lname FROM customer WHERE id = 1",
     );
+    let ast = query(
+        "SELECT foo FROM bar LIMIT ALL OFFSET 5",
+        "SELECT foo FROM bar OFFSET 5",
+    );
+    assert_eq!(ast.limit, None);
+    assert_eq!(ast.offset, Some("5".to_string()));
 }
 
 #[test]
@@ -3166,6 +3236,8 @@ fn parse_offset() {
     assert_eq!(ast.offset, Some("1".to_string()));
     let ast = verified_query("SELECT 'foo' OFFSET 1");
     assert_eq!(ast.offset, Some("1".to_string()));
+    let ast = verified_query("SELECT 'foo' OFFSET -1");
+    assert_eq!(ast.offset, Some("-1".to_string()));
 }
 
 #[test]
@@ -607,6 +607,33 @@ fn parse_prepare() {
     );
 }
 
+#[test]
+fn parse_parameters() {
+    let select = verified_only_select("SELECT * FROM customers WHERE id = $1 AND name = $2");
+    assert_eq!(
+        select.selection,
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("id".into())),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Parameter { index: 1 }),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("name".into())),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Parameter { index: 2 }),
+            }),
+        })
+    );
+
+    let res = parse_sql_statements("SELECT $0");
+    assert_eq!(
+        ParserError::ParserError("invalid parameter $0".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_pg_bitwise_binary_ops() {
     let bitwise_ops = &[
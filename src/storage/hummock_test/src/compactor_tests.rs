@@ -271,6 +271,20 @@ mod tests {
             )
             .await;
         assert!(ret.is_err());
+
+        // A time-travel read is bounded by the safe epoch, which the watermark has advanced.
+        let get_val = storage
+            .get_at_epoch(&key, (32 * 1000) << 16, Default::default())
+            .await
+            .unwrap()
+            .unwrap()
+            .to_vec();
+        assert_eq!(get_val, val);
+        let err = storage
+            .get_at_epoch(&key, (31 * 1000) << 16, Default::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Expired Epoch"), "{}", err);
     }
 
     #[tokio::test]
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use bytes::Bytes;
+use risingwave_hummock_sdk::key::{next_key, range_of_prefix};
 use risingwave_hummock_sdk::{HummockEpoch, HummockReadEpoch};
 use risingwave_meta::hummock::test_utils::setup_compute_env;
 use risingwave_meta::hummock::MockHummockMetaClient;
@@ -23,7 +24,7 @@ use risingwave_storage::hummock::iterator::test_utils::mock_sstable_store;
 use risingwave_storage::hummock::test_utils::{count_iter, default_config_for_test};
 use risingwave_storage::hummock::HummockStorage;
 use risingwave_storage::storage_value::StorageValue;
-use risingwave_storage::store::{ReadOptions, StateStore, WriteOptions};
+use risingwave_storage::store::{DirectionEnum, ReadOptions, StateStore, WriteOptions};
 use risingwave_storage::StateStoreIter;
 
 use crate::test_utils::get_test_notification_client;
@@ -1115,3 +1116,668 @@ async fn test_multiple_epoch_sync() {
         .unwrap();
     test_get().await;
 }
+
+#[tokio::test]
+async fn test_iter_direction() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa"), StorageValue::new_put("111")),
+                (Bytes::from("bb"), StorageValue::new_put("222")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let read_options = ReadOptions {
+        epoch: epoch1,
+        table_id: Default::default(),
+        retention_seconds: None,
+    };
+
+    let mut iter = hummock_storage
+        .iter(None, ..=b"ee".to_vec(), read_options.clone())
+        .await
+        .unwrap();
+    assert_eq!(iter.direction(), Some(DirectionEnum::Forward));
+    let (first, _) = iter.next().await.unwrap().unwrap();
+    assert_eq!(first, Bytes::from("aa"));
+
+    let mut iter = hummock_storage
+        .backward_iter(..=b"ee".to_vec(), read_options)
+        .await
+        .unwrap();
+    assert_eq!(iter.direction(), Some(DirectionEnum::Backward));
+    let (first, _) = iter.next().await.unwrap().unwrap();
+    assert_eq!(first, Bytes::from("bb"));
+}
+
+#[tokio::test]
+async fn test_get_at_epoch() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa"), StorageValue::new_put("111")),
+                (Bytes::from("bb"), StorageValue::new_put("222")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let epoch2 = epoch1 + 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa"), StorageValue::new_put("333")),
+                (Bytes::from("bb"), StorageValue::new_delete()),
+                (Bytes::from("cc"), StorageValue::new_put("444")),
+            ],
+            WriteOptions {
+                epoch: epoch2,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    // Reading at an epoch sees its snapshot, both before and after it is committed.
+    for sync in [false, true] {
+        if sync {
+            hummock_storage.seal_and_sync_epoch(epoch1).await.unwrap();
+            hummock_storage.seal_and_sync_epoch(epoch2).await.unwrap();
+        }
+        for (key, epoch, expected) in [
+            ("aa", epoch1, Some("111")),
+            ("bb", epoch1, Some("222")),
+            ("cc", epoch1, None),
+            ("aa", epoch2, Some("333")),
+            ("bb", epoch2, None),
+            ("cc", epoch2, Some("444")),
+        ] {
+            let value = hummock_storage
+                .get_at_epoch(key.as_bytes(), epoch, Default::default())
+                .await
+                .unwrap();
+            assert_eq!(value, expected.map(Bytes::from));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_count_prefix() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa1"), StorageValue::new_put("111")),
+                (Bytes::from("aa2"), StorageValue::new_put("222")),
+                (Bytes::from("aa3"), StorageValue::new_put("333")),
+                (Bytes::from("ab1"), StorageValue::new_put("444")),
+                (Bytes::from("b1"), StorageValue::new_put("555")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    // Deleted keys must not be counted.
+    let epoch2 = epoch1 + 1;
+    hummock_storage
+        .ingest_batch(
+            vec![(Bytes::from("aa3"), StorageValue::new_delete())],
+            WriteOptions {
+                epoch: epoch2,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    for (prefix, epoch, expected) in [
+        (&b"aa"[..], epoch1, 3),
+        (b"aa", epoch2, 2),
+        (b"a", epoch2, 3),
+        (b"b", epoch2, 1),
+        (b"c", epoch2, 0),
+        (b"", epoch2, 4),
+    ] {
+        let read_options = ReadOptions {
+            epoch,
+            table_id: Default::default(),
+            retention_seconds: None,
+        };
+        let count = hummock_storage
+            .count_prefix(prefix, read_options.clone())
+            .await
+            .unwrap();
+        let mut iter = hummock_storage
+            .iter(None, range_of_prefix(prefix), read_options)
+            .await
+            .unwrap();
+        assert_eq!(count, count_iter(&mut iter).await);
+        assert_eq!(count, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_iter_with_upper() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("a"), StorageValue::new_put("000")),
+                (Bytes::from("aa1"), StorageValue::new_put("111")),
+                (Bytes::from("aa2"), StorageValue::new_put("222")),
+                (Bytes::from("ab1"), StorageValue::new_put("333")),
+                (
+                    Bytes::from_static(b"\xff\xff"),
+                    StorageValue::new_put("444"),
+                ),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let epoch2 = epoch1 + 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa2"), StorageValue::new_delete()),
+                (Bytes::from("aa3"), StorageValue::new_put("555")),
+            ],
+            WriteOptions {
+                epoch: epoch2,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    // The prefix `\xff` has no upper bound.
+    for prefix in [&b"aa"[..], b"a", b"\xff", b"c", b""] {
+        // The bound is computed once and reused for the scans at every epoch.
+        let upper = next_key(prefix);
+        for epoch in [epoch1, epoch2] {
+            let read_options = ReadOptions {
+                epoch,
+                table_id: Default::default(),
+                retention_seconds: None,
+            };
+            let mut expected = hummock_storage
+                .iter(None, range_of_prefix(prefix), read_options.clone())
+                .await
+                .unwrap();
+            let mut iter = hummock_storage
+                .iter_with_upper(prefix, &upper, read_options)
+                .await
+                .unwrap();
+            while let Some(item) = expected.next().await.unwrap() {
+                assert_eq!(iter.next().await.unwrap(), Some(item));
+            }
+            assert!(iter.next().await.unwrap().is_none());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_iter_next_ref() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa"), StorageValue::new_put("111")),
+                (Bytes::from("bb"), StorageValue::new_put("222")),
+                (Bytes::from("cc"), StorageValue::new_put("333")),
+                (Bytes::from("dd"), StorageValue::new_put("444")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+    let read_options = ReadOptions {
+        epoch: epoch1,
+        table_id: Default::default(),
+        retention_seconds: None,
+    };
+
+    // The entries are borrowed from the iterator, so only their lengths outlive each step.
+    let mut iter = hummock_storage
+        .iter(None, b"bb".to_vec()..b"dd".to_vec(), read_options.clone())
+        .await
+        .unwrap();
+    let mut total_len = 0;
+    let (key, value) = iter.next_ref().await.unwrap().unwrap();
+    assert_eq!((key, value), (&b"bb"[..], &b"222"[..]));
+    total_len += key.len() + value.len();
+    let (key, value) = iter.next_ref().await.unwrap().unwrap();
+    assert_eq!((key, value), (&b"cc"[..], &b"333"[..]));
+    total_len += key.len() + value.len();
+    assert!(iter.next_ref().await.unwrap().is_none());
+    assert_eq!(total_len, 10);
+
+    // Borrowing and owned iteration can be mixed without skipping or repeating an entry.
+    let mut iter = hummock_storage
+        .iter(None, b"aa".to_vec()..=b"dd".to_vec(), read_options)
+        .await
+        .unwrap();
+    assert_eq!(iter.next_ref().await.unwrap().unwrap().0, b"aa");
+    let (key, _) = iter.next().await.unwrap().unwrap();
+    assert_eq!(key, Bytes::from("bb"));
+    assert_eq!(iter.next_ref().await.unwrap().unwrap().0, b"cc");
+    assert_eq!(count_iter(&mut iter).await, 1);
+}
+
+#[tokio::test]
+async fn test_iter_peek() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa"), StorageValue::new_put("111")),
+                (Bytes::from("bb"), StorageValue::new_put("222")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let mut iter = hummock_storage
+        .iter(
+            None,
+            b"aa".to_vec()..=b"bb".to_vec(),
+            ReadOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+                retention_seconds: None,
+            },
+        )
+        .await
+        .unwrap();
+    for expected in [("aa", "111"), ("bb", "222")] {
+        let peeked = iter
+            .peek()
+            .await
+            .unwrap()
+            .map(|(key, value)| (Bytes::copy_from_slice(key), Bytes::copy_from_slice(value)));
+        // Peeking again does not advance the iterator.
+        assert_eq!(
+            iter.peek().await.unwrap().map(|(key, _)| key),
+            Some(expected.0.as_bytes())
+        );
+        let next = iter.next().await.unwrap();
+        assert_eq!(peeked, next);
+        assert_eq!(
+            next,
+            Some((Bytes::from(expected.0), Bytes::from(expected.1)))
+        );
+    }
+    assert!(iter.peek().await.unwrap().is_none());
+    assert!(iter.next().await.unwrap().is_none());
+
+    // Peeking right after `next_ref` skips the borrowed entry.
+    let mut iter = hummock_storage
+        .iter(
+            None,
+            b"aa".to_vec()..=b"bb".to_vec(),
+            ReadOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+                retention_seconds: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(iter.next_ref().await.unwrap().unwrap().0, b"aa");
+    assert_eq!(
+        iter.peek().await.unwrap().map(|(key, _)| key),
+        Some(b"bb".as_slice())
+    );
+    assert_eq!(
+        iter.next().await.unwrap().map(|(key, _)| key),
+        Some(Bytes::from("bb"))
+    );
+}
+
+#[tokio::test]
+async fn test_delete_prefix() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa1"), StorageValue::new_put("111")),
+                (Bytes::from("aa2"), StorageValue::new_put("222")),
+                (Bytes::from("ab1"), StorageValue::new_put("333")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let epoch2 = epoch1 + 1;
+    let deleted = hummock_storage
+        .delete_prefix(
+            b"aa",
+            WriteOptions {
+                epoch: epoch2,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(deleted, 2);
+
+    let read_options = ReadOptions {
+        epoch: epoch2,
+        table_id: Default::default(),
+        retention_seconds: None,
+    };
+    let mut iter = hummock_storage
+        .iter(None, range_of_prefix(b"aa"), read_options.clone())
+        .await
+        .unwrap();
+    assert!(iter.next().await.unwrap().is_none());
+
+    // Keys outside the prefix are untouched.
+    let mut iter = hummock_storage
+        .iter(None, range_of_prefix(b"a"), read_options)
+        .await
+        .unwrap();
+    assert_eq!(count_iter(&mut iter).await, 1);
+
+    // The deleted keys are still visible at the previous epoch.
+    let mut iter = hummock_storage
+        .iter(
+            None,
+            range_of_prefix(b"aa"),
+            ReadOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+                retention_seconds: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(count_iter(&mut iter).await, 2);
+}
+
+#[tokio::test]
+async fn test_ingest_empty_batch() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    let epoch1 = 1;
+    let size = hummock_storage
+        .ingest_batch(
+            vec![],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(size, 0);
+
+    // The epoch can still be synced as usual.
+    let sync_result = hummock_storage.seal_and_sync_epoch(epoch1).await.unwrap();
+    assert_eq!(sync_result.sync_size, 0);
+    assert!(sync_result.uncommitted_ssts.is_empty());
+
+    // Writes in later epochs are not affected.
+    let epoch2 = epoch1 + 1;
+    hummock_storage
+        .ingest_batch(
+            vec![(Bytes::from("aa"), StorageValue::new_put("111"))],
+            WriteOptions {
+                epoch: epoch2,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+    let value = hummock_storage
+        .get(
+            b"aa",
+            true,
+            ReadOptions {
+                epoch: epoch2,
+                table_id: Default::default(),
+                retention_seconds: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(value, Some(Bytes::from("111")));
+}
+
+#[tokio::test]
+async fn test_ingest_batch_duplicate_keys() {
+    let sstable_store = mock_sstable_store();
+    let hummock_options = Arc::new(default_config_for_test());
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let hummock_storage = HummockStorage::for_test(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        get_test_notification_client(env, hummock_manager_ref, worker_node),
+    )
+    .await
+    .unwrap();
+
+    // The last operation on a key in the batch wins.
+    let epoch1 = 1;
+    hummock_storage
+        .ingest_batch(
+            vec![
+                (Bytes::from("aa"), StorageValue::new_put("111")),
+                (Bytes::from("aa"), StorageValue::new_delete()),
+                (Bytes::from("bb"), StorageValue::new_delete()),
+                (Bytes::from("bb"), StorageValue::new_put("222")),
+                (Bytes::from("bb"), StorageValue::new_put("333")),
+            ],
+            WriteOptions {
+                epoch: epoch1,
+                table_id: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let read_options = ReadOptions {
+        epoch: epoch1,
+        table_id: Default::default(),
+        retention_seconds: None,
+    };
+    let value = hummock_storage
+        .get(b"aa", true, read_options.clone())
+        .await
+        .unwrap();
+    assert_eq!(value, None);
+    let value = hummock_storage
+        .get(b"bb", true, read_options.clone())
+        .await
+        .unwrap();
+    assert_eq!(value, Some(Bytes::from("333")));
+
+    let mut iter = hummock_storage
+        .iter(None, ..=b"ee".to_vec(), read_options)
+        .await
+        .unwrap();
+    assert_eq!(count_iter(&mut iter).await, 1);
+
+    // Only one entry of each key reaches the shared buffer, so the epoch can be synced.
+    hummock_storage.seal_and_sync_epoch(epoch1).await.unwrap();
+}
@@ -13,38 +13,47 @@
 // limitations under the License.
 
 //! This mod implements a `ConflictDetector` that  detect write key conflict in each epoch
-use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use bytes::Bytes;
 use crossbeam::atomic::AtomicCell;
 use dashmap::DashMap;
-use risingwave_common::config::StorageConfig;
+use itertools::Itertools;
+use risingwave_common::catalog::TableId;
+use risingwave_common::config::{StorageConfig, WriteConflictDetectionMode};
 
 use crate::hummock::value::HummockValue;
-use crate::hummock::HummockEpoch;
+use crate::hummock::{HummockEpoch, HummockError, HummockResult};
 
 pub struct ConflictDetector {
-    // epoch -> key-sets
-    epoch_history: DashMap<HummockEpoch, Option<HashSet<Bytes>>>,
+    // epoch -> table id -> key -> value-hash
+    epoch_history: DashMap<HummockEpoch, Option<HashMap<TableId, HashMap<Bytes, u64>>>>,
     epoch_watermark: AtomicCell<HummockEpoch>,
+    mode: WriteConflictDetectionMode,
 }
 
 impl Default for ConflictDetector {
     fn default() -> Self {
+        Self::new(WriteConflictDetectionMode::Panic)
+    }
+}
+
+impl ConflictDetector {
+    pub fn new(mode: WriteConflictDetectionMode) -> Self {
         Self {
             epoch_history: DashMap::new(),
             epoch_watermark: AtomicCell::new(HummockEpoch::MIN),
+            mode,
         }
     }
-}
 
-impl ConflictDetector {
     pub fn new_from_config(options: Arc<StorageConfig>) -> Option<Arc<ConflictDetector>> {
-        if options.write_conflict_detection_enabled {
-            Some(Arc::new(ConflictDetector::default()))
-        } else {
-            None
+        match options.write_conflict_detection_mode() {
+            WriteConflictDetectionMode::Off => None,
+            mode => Some(Arc::new(ConflictDetector::new(mode))),
         }
     }
 
@@ -75,36 +84,207 @@ impl ConflictDetector {
         }
     }
 
+    /// Forgets all tracked writes and archived epochs, and resets the watermark, so that the
+    /// detector can be reused from scratch. Only for tests, and must not be called while writes
+    /// are in flight.
+    pub fn reset(&self) {
+        self.epoch_history.clear();
+        self.epoch_watermark.store(HummockEpoch::MIN);
+    }
+
     /// Checks whether there is key conflict for the given `kv_pairs` and adds the key in `kv_pairs`
-    /// to the tracking history. Besides, whether the `epoch` has been archived will also be checked
-    /// to avoid writing to a stale epoch
+    /// to the tracking history. Writing a key again with an identical value is an idempotent retry
+    /// and is not a conflict. Within one batch, a delete of a key may also be followed by a single
+    /// put of it, which collapses to the put. Besides, whether the `epoch` has been archived will
+    /// also be checked to avoid writing to a stale epoch
+    ///
+    /// A conflict panics or returns an error depending on the mode of the detector. In `Warn`
+    /// mode, it is logged and the whole batch is tracked, so the last write wins.
+    ///
+    /// The keys are not attributed to any table. See
+    /// [`ConflictDetector::check_conflict_and_track_table_write_batch`] to track them per table.
     pub fn check_conflict_and_track_write_batch(
         &self,
         kv_pairs: &[(Bytes, HummockValue<Bytes>)],
         epoch: HummockEpoch,
-    ) {
+    ) -> HummockResult<()> {
+        self.check_conflict_and_track_table_write_batch(kv_pairs, epoch, TableId::default())
+    }
+
+    /// Same as [`ConflictDetector::check_conflict_and_track_write_batch`], but the keys are only
+    /// checked against the keys written by the same table, and conflicts are reported with the
+    /// table.
+    pub fn check_conflict_and_track_table_write_batch(
+        &self,
+        kv_pairs: &[(Bytes, HummockValue<Bytes>)],
+        epoch: HummockEpoch,
+        table_id: TableId,
+    ) -> HummockResult<()> {
+        self.assert_not_archived(epoch);
+
+        let mut epoch_writes = self
+            .epoch_history
+            .entry(epoch)
+            .or_insert(Some(HashMap::new()));
+        let written_key = epoch_writes
+            .as_mut()
+            .unwrap_or_else(|| panic!("write to an archived epoch: {}", epoch))
+            .entry(table_id)
+            .or_default();
+
+        let key = match Self::batch_writes(written_key, kv_pairs) {
+            Ok(batch_writes) => {
+                written_key.extend(batch_writes);
+                return Ok(());
+            }
+            Err(key) => key,
+        };
+        match self.mode {
+            WriteConflictDetectionMode::Off | WriteConflictDetectionMode::Warn => {
+                if self.mode == WriteConflictDetectionMode::Warn {
+                    tracing::warn!(
+                        "key {:?} of table {} is written again with a different value in epoch {}",
+                        key,
+                        table_id,
+                        epoch
+                    );
+                }
+                written_key.extend(
+                    kv_pairs
+                        .iter()
+                        .map(|(key, value)| (key.clone(), Self::value_hash(value))),
+                );
+                Ok(())
+            }
+            WriteConflictDetectionMode::Error => Err(HummockError::other(format!(
+                "key {:?} of table {} is written again with a different value in epoch {}",
+                key, table_id, epoch
+            ))),
+            WriteConflictDetectionMode::Panic => panic!(
+                "key {:?} of table {} is written again with a different value in epoch {}",
+                key, table_id, epoch
+            ),
+        }
+    }
+
+    /// Returns the first key in `kv_pairs` that would conflict if the batch was written to
+    /// `epoch`, following the same rules as
+    /// [`ConflictDetector::check_conflict_and_track_write_batch`]. Nothing is tracked, so the
+    /// batch can still be written afterwards.
+    pub fn would_conflict(
+        &self,
+        kv_pairs: &[(Bytes, HummockValue<Bytes>)],
+        epoch: HummockEpoch,
+    ) -> Option<Bytes> {
+        self.would_conflict_in_table(kv_pairs, epoch, TableId::default())
+    }
+
+    /// Same as [`ConflictDetector::would_conflict`], but the keys are only checked against the
+    /// keys written by the same table, like
+    /// [`ConflictDetector::check_conflict_and_track_table_write_batch`].
+    pub fn would_conflict_in_table(
+        &self,
+        kv_pairs: &[(Bytes, HummockValue<Bytes>)],
+        epoch: HummockEpoch,
+        table_id: TableId,
+    ) -> Option<Bytes> {
+        self.assert_not_archived(epoch);
+
+        let empty = HashMap::new();
+        let epoch_writes = self.epoch_history.get(&epoch);
+        let written_key = match epoch_writes.as_deref() {
+            Some(Some(epoch_writes)) => epoch_writes.get(&table_id).unwrap_or(&empty),
+            Some(None) => panic!("write to an archived epoch: {}", epoch),
+            None => &empty,
+        };
+        Self::batch_writes(written_key, kv_pairs).err()
+    }
+
+    fn assert_not_archived(&self, epoch: HummockEpoch) {
         assert!(
             epoch > self.get_epoch_watermark(),
             "write to an archived epoch: {}",
             epoch
         );
+    }
 
-        let mut written_key = self
-            .epoch_history
-            .entry(epoch)
-            .or_insert(Some(HashSet::new()));
-
+    /// Returns the value hashes that writing `kv_pairs` on top of `written_key` would track, or the
+    /// first conflicting key.
+    fn batch_writes(
+        written_key: &HashMap<Bytes, u64>,
+        kv_pairs: &[(Bytes, HummockValue<Bytes>)],
+    ) -> Result<HashMap<Bytes, u64>, Bytes> {
+        let mut batch_writes = HashMap::new();
+        // Keys deleted in this batch that may still be put once.
+        let mut deleted_in_batch = HashSet::new();
         for (key, value) in kv_pairs.iter() {
-            assert!(
-                written_key
-                    .as_mut()
-                    .unwrap_or_else(|| panic!("write to an archived epoch: {}", epoch))
-                    .insert(key.clone()),
-                "key {:?} is written again after previously written, value is {:?}",
-                key,
-                value,
-            );
+            let value_hash = Self::value_hash(value);
+            let written_hash = batch_writes
+                .get(key)
+                .or_else(|| written_key.get(key))
+                .copied();
+            match written_hash {
+                Some(_)
+                    if matches!(value, HummockValue::Put(_)) && deleted_in_batch.remove(key) =>
+                {
+                    batch_writes.insert(key.clone(), value_hash);
+                }
+                Some(written_hash) if written_hash != value_hash => return Err(key.clone()),
+                Some(_) => {}
+                None => {
+                    if matches!(value, HummockValue::Delete) {
+                        deleted_in_batch.insert(key);
+                    }
+                    batch_writes.insert(key.clone(), value_hash);
+                }
+            }
         }
+        Ok(batch_writes)
+    }
+
+    fn value_hash(value: &HummockValue<Bytes>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        match value {
+            HummockValue::Put(v) => {
+                0u8.hash(&mut hasher);
+                v.hash(&mut hasher);
+            }
+            HummockValue::Delete => 1u8.hash(&mut hasher),
+        }
+        hasher.finish()
+    }
+
+    /// Returns a snapshot of the keys tracked for `epoch` in any table, sorted and deduplicated.
+    /// Returns `None` if nothing has been written to `epoch` or it has been archived. Only for
+    /// debugging.
+    pub fn dump_epoch(&self, epoch: HummockEpoch) -> Option<Vec<Bytes>> {
+        self.epoch_history
+            .get(&epoch)
+            .and_then(|written_key| written_key.as_ref().map(Self::sorted_keys))
+    }
+
+    /// Returns a snapshot of the keys tracked for every epoch that is not archived yet. Only for
+    /// debugging.
+    pub fn dump_all(&self) -> HashMap<HummockEpoch, Vec<Bytes>> {
+        self.epoch_history
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .value()
+                    .as_ref()
+                    .map(|written_key| (*entry.key(), Self::sorted_keys(written_key)))
+            })
+            .collect()
+    }
+
+    fn sorted_keys(epoch_writes: &HashMap<TableId, HashMap<Bytes, u64>>) -> Vec<Bytes> {
+        let mut keys = epoch_writes
+            .values()
+            .flat_map(|written_key| written_key.keys().cloned())
+            .collect_vec();
+        keys.sort();
+        keys.dedup();
+        keys
     }
 
     /// Archives an epoch. An archived epoch cannot be written anymore.
@@ -115,113 +295,407 @@ impl ConflictDetector {
             epochs,
             self.get_epoch_watermark(),
         );
-        epochs.into_iter().for_each(|epoch| {
-            if let Some(written_key) = self.epoch_history.get(&epoch) {
-                assert!(
-                    written_key.is_some(),
-                    "epoch has been archived: epoch is {}",
-                    epoch
-                );
-            }
-            self.epoch_history.insert(epoch, None);
-        })
+        epochs
+            .into_iter()
+            .for_each(|epoch| self.archive_one_epoch(epoch))
+    }
+
+    /// Archives a run of `epochs` committed together, and then prunes all archived epochs below
+    /// `first_epoch` by advancing the watermark to `first_epoch - 1`. `first_epoch` must not be
+    /// greater than any of `epochs`. Unlike calling [`ConflictDetector::set_watermark`] per epoch,
+    /// the history is only pruned with a single `retain`.
+    pub fn archive_epochs(&self, epochs: &[HummockEpoch], first_epoch: HummockEpoch) {
+        assert!(
+            epochs
+                .iter()
+                .all(|epoch| *epoch > self.get_epoch_watermark()),
+            "write to an archived epoch: {:?} , current_epoch :{}",
+            epochs,
+            self.get_epoch_watermark(),
+        );
+        assert!(
+            epochs.iter().min().map_or(true, |min| first_epoch <= *min),
+            "first epoch {} is greater than the archived epochs: {:?}",
+            first_epoch,
+            epochs,
+        );
+        epochs
+            .iter()
+            .for_each(|epoch| self.archive_one_epoch(*epoch));
+        self.set_watermark(first_epoch.saturating_sub(1));
+    }
+
+    fn archive_one_epoch(&self, epoch: HummockEpoch) {
+        if let Some(written_key) = self.epoch_history.get(&epoch) {
+            assert!(
+                written_key.is_some(),
+                "epoch has been archived: epoch is {}",
+                epoch
+            );
+        }
+        self.epoch_history.insert(epoch, None);
     }
 }
 
 #[cfg(test)]
 mod test {
     use std::iter::once;
+    use std::sync::Arc;
 
     use bytes::Bytes;
     use itertools::Itertools;
+    use risingwave_common::catalog::TableId;
+    use risingwave_common::config::{StorageConfig, WriteConflictDetectionMode};
 
     use crate::hummock::conflict_detector::ConflictDetector;
     use crate::hummock::value::HummockValue;
+    use crate::hummock::HummockEpoch;
 
     #[test]
     #[should_panic]
     fn test_write_conflict_in_one_batch() {
         let detector = ConflictDetector::default();
-        detector.check_conflict_and_track_write_batch(
-            (0..2)
-                .map(|_| (Bytes::from("conflicted-key"), HummockValue::Delete))
-                .into_iter()
-                .collect_vec()
+        detector
+            .check_conflict_and_track_write_batch(
+                [
+                    (
+                        Bytes::from("conflicted-key"),
+                        HummockValue::Put(Bytes::from("v1")),
+                    ),
+                    (
+                        Bytes::from("conflicted-key"),
+                        HummockValue::Put(Bytes::from("v2")),
+                    ),
+                ]
                 .as_slice(),
-            233,
-        );
+                233,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_delete_then_put_in_one_batch() {
+        let detector = ConflictDetector::default();
+        detector
+            .check_conflict_and_track_write_batch(
+                [
+                    (Bytes::from("key"), HummockValue::Delete),
+                    (Bytes::from("key"), HummockValue::Put(Bytes::from("value"))),
+                ]
+                .as_slice(),
+                233,
+            )
+            .unwrap();
+        // The pair collapses to the put, so retrying the put alone is idempotent.
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key"), HummockValue::Put(Bytes::from("value"))))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_delete_then_multiple_puts_in_one_batch() {
+        let detector = ConflictDetector::default();
+        detector
+            .check_conflict_and_track_write_batch(
+                [
+                    (Bytes::from("key"), HummockValue::Delete),
+                    (Bytes::from("key"), HummockValue::Put(Bytes::from("v1"))),
+                    (Bytes::from("key"), HummockValue::Put(Bytes::from("v2"))),
+                ]
+                .as_slice(),
+                233,
+            )
+            .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn test_write_conflict_in_multi_batch() {
         let detector = ConflictDetector::default();
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("conflicted-key"), HummockValue::Delete))
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("conflicted-key"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((
+                    Bytes::from("conflicted-key"),
+                    HummockValue::Put(Bytes::from("value")),
+                ))
                 .collect_vec()
                 .as_slice(),
-            233,
+                233,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_conflict_with_different_put_value() {
+        let detector = ConflictDetector::default();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key"), HummockValue::Put(Bytes::from("v1"))))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key"), HummockValue::Put(Bytes::from("v2"))))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_write_conflict_warn_mode() {
+        let detector = ConflictDetector::new(WriteConflictDetectionMode::Warn);
+        for value in ["v1", "v2"] {
+            detector
+                .check_conflict_and_track_write_batch(
+                    &[(Bytes::from("key"), HummockValue::Put(Bytes::from(value)))],
+                    233,
+                )
+                .unwrap();
+        }
+        // The last write wins, so rewriting it is idempotent while the first one conflicts.
+        assert!(detector
+            .would_conflict(
+                &[(Bytes::from("key"), HummockValue::Put(Bytes::from("v2")))],
+                233
+            )
+            .is_none());
+        assert!(detector
+            .would_conflict(
+                &[(Bytes::from("key"), HummockValue::Put(Bytes::from("v1")))],
+                233
+            )
+            .is_some());
+    }
+
+    #[test]
+    fn test_write_conflict_error_mode() {
+        let detector = ConflictDetector::new(WriteConflictDetectionMode::Error);
+        detector
+            .check_conflict_and_track_write_batch(
+                &[(Bytes::from("key"), HummockValue::Put(Bytes::from("v1")))],
+                233,
+            )
+            .unwrap();
+        assert!(detector
+            .check_conflict_and_track_write_batch(
+                &[
+                    (Bytes::from("key2"), HummockValue::Delete),
+                    (Bytes::from("key"), HummockValue::Put(Bytes::from("v2"))),
+                ],
+                233,
+            )
+            .is_err());
+        // The rejected batch is not tracked.
+        assert_eq!(detector.dump_epoch(233).unwrap(), vec![Bytes::from("key")]);
+    }
+
+    #[test]
+    fn test_mode_from_config() {
+        let mode = |enabled, mode| {
+            ConflictDetector::new_from_config(Arc::new(StorageConfig {
+                write_conflict_detection_enabled: enabled,
+                write_conflict_detection_mode: mode,
+                ..Default::default()
+            }))
+            .map(|detector| detector.mode)
+        };
+        assert_eq!(mode(true, None), Some(WriteConflictDetectionMode::Panic));
+        assert_eq!(mode(false, None), None);
+        assert_eq!(
+            mode(false, Some(WriteConflictDetectionMode::Warn)),
+            Some(WriteConflictDetectionMode::Warn)
         );
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("conflicted-key"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
+        assert_eq!(mode(true, Some(WriteConflictDetectionMode::Off)), None);
+    }
+
+    #[test]
+    fn test_write_same_key_in_different_tables() {
+        let detector = ConflictDetector::default();
+        for (table_id, value) in [(1, "v1"), (2, "v2")] {
+            detector
+                .check_conflict_and_track_table_write_batch(
+                    &[(Bytes::from("key"), HummockValue::Put(Bytes::from(value)))],
+                    233,
+                    TableId::new(table_id),
+                )
+                .unwrap();
+        }
+        // Table-agnostic writes do not conflict with either table.
+        detector
+            .check_conflict_and_track_write_batch(
+                &[(Bytes::from("key"), HummockValue::Delete)],
+                233,
+            )
+            .unwrap();
+        assert_eq!(detector.dump_epoch(233).unwrap(), vec![Bytes::from("key")]);
+    }
+
+    #[test]
+    fn test_write_conflict_in_one_table() {
+        let detector = ConflictDetector::new(WriteConflictDetectionMode::Error);
+        let write = |value: &str| {
+            detector.check_conflict_and_track_table_write_batch(
+                &[(Bytes::from("key"), HummockValue::Put(Bytes::from(value)))],
+                233,
+                TableId::new(1),
+            )
+        };
+        write("v1").unwrap();
+        let would_conflict = |value: &str, table_id: u32| {
+            detector.would_conflict_in_table(
+                &[(Bytes::from("key"), HummockValue::Put(Bytes::from(value)))],
+                233,
+                TableId::new(table_id),
+            )
+        };
+        assert_eq!(would_conflict("v2", 1), Some(Bytes::from("key")));
+        assert!(would_conflict("v1", 1).is_none());
+        assert!(would_conflict("v2", 2).is_none());
+        let err = write("v2").unwrap_err();
+        assert!(err.to_string().contains("of table 1"), "{}", err);
+    }
+
+    #[test]
+    fn test_idempotent_rewrite() {
+        let detector = ConflictDetector::default();
+        for _ in 0..2 {
+            detector
+                .check_conflict_and_track_write_batch(
+                    [
+                        (Bytes::from("key1"), HummockValue::Put(Bytes::from("value"))),
+                        (Bytes::from("key1"), HummockValue::Put(Bytes::from("value"))),
+                        (Bytes::from("key2"), HummockValue::Delete),
+                    ]
+                    .as_slice(),
+                    233,
+                )
+                .unwrap();
+        }
+        assert_eq!(
+            detector.epoch_history.get(&233).unwrap().as_ref().unwrap()[&TableId::default()].len(),
+            2
         );
     }
 
     #[test]
-    fn test_valid_write_in_multi_batch() {
+    fn test_would_conflict() {
         let detector = ConflictDetector::default();
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
+        let batch = [
+            (Bytes::from("key1"), HummockValue::Delete),
+            (Bytes::from("key1"), HummockValue::Put(Bytes::from("v1"))),
+            (Bytes::from("key2"), HummockValue::Put(Bytes::from("v2"))),
+        ];
+        assert!(detector.would_conflict(&batch, 233).is_none());
+        assert!(detector.dump_epoch(233).is_none());
+        detector
+            .check_conflict_and_track_write_batch(&batch, 233)
+            .unwrap();
+        assert_eq!(
+            detector.dump_epoch(233).unwrap(),
+            vec![Bytes::from("key1"), Bytes::from("key2")]
         );
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key2"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
+
+        assert!(detector.would_conflict(&batch[1..], 233).is_none());
+        assert_eq!(
+            detector.would_conflict(
+                &[
+                    (Bytes::from("key3"), HummockValue::Delete),
+                    (Bytes::from("key2"), HummockValue::Put(Bytes::from("v3"))),
+                ],
+                233
+            ),
+            Some(Bytes::from("key2"))
         );
-        detector.archive_epoch(vec![233]);
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            234,
+        assert_eq!(
+            detector.dump_epoch(233).unwrap(),
+            vec![Bytes::from("key1"), Bytes::from("key2")]
         );
     }
 
+    #[test]
+    fn test_valid_write_in_multi_batch() {
+        let detector = ConflictDetector::default();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key2"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+        detector.archive_epoch(vec![233]);
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                234,
+            )
+            .unwrap();
+    }
+
     #[test]
     #[should_panic]
     fn test_write_to_archived_epoch() {
         let detector = ConflictDetector::default();
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
-        );
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
         detector.archive_epoch(vec![233]);
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
-        );
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
     }
 
     #[test]
     fn test_clear_key_after_epoch_archive() {
         let detector = ConflictDetector::default();
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
-        );
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
         assert!(detector.epoch_history.get(&233).unwrap().is_some());
         detector.archive_epoch(vec![233]);
         assert!(detector.epoch_history.get(&233).unwrap().is_none());
@@ -230,21 +704,120 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
-    fn test_write_below_epoch_watermark() {
+    fn test_reset() {
         let detector = ConflictDetector::default();
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
-                .as_slice(),
-            233,
-        );
+        let write = |value: &str| {
+            detector
+                .check_conflict_and_track_write_batch(
+                    &[(Bytes::from("key"), HummockValue::Put(Bytes::from(value)))],
+                    233,
+                )
+                .unwrap()
+        };
+        write("v1");
+        detector.archive_epoch(vec![233]);
         detector.set_watermark(233);
-        detector.check_conflict_and_track_write_batch(
-            once((Bytes::from("key1"), HummockValue::Delete))
-                .collect_vec()
+        assert_eq!(detector.get_epoch_watermark(), 233);
+
+        detector.reset();
+        assert_eq!(detector.get_epoch_watermark(), HummockEpoch::MIN);
+        assert!(detector.dump_all().is_empty());
+        // Epoch 233 is neither archived nor below the watermark anymore, and the previous value is
+        // forgotten.
+        write("v2");
+        assert_eq!(detector.dump_epoch(233).unwrap(), vec![Bytes::from("key")]);
+    }
+
+    #[test]
+    fn test_dump() {
+        let detector = ConflictDetector::default();
+        detector
+            .check_conflict_and_track_write_batch(
+                [
+                    (Bytes::from("key2"), HummockValue::Delete),
+                    (Bytes::from("key1"), HummockValue::Put(Bytes::from("value"))),
+                ]
                 .as_slice(),
-            232,
+                233,
+            )
+            .unwrap();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key3"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                234,
+            )
+            .unwrap();
+        assert_eq!(
+            detector.dump_epoch(233).unwrap(),
+            vec![Bytes::from("key1"), Bytes::from("key2")]
         );
+        assert!(detector.dump_epoch(235).is_none());
+
+        detector.archive_epoch(vec![233]);
+        assert!(detector.dump_epoch(233).is_none());
+        let all = detector.dump_all();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[&234], vec![Bytes::from("key3")]);
+    }
+
+    #[test]
+    fn test_archive_epochs() {
+        let detector = ConflictDetector::default();
+        for epoch in 233..=237 {
+            detector
+                .check_conflict_and_track_write_batch(
+                    once((Bytes::from("key1"), HummockValue::Delete))
+                        .collect_vec()
+                        .as_slice(),
+                    epoch,
+                )
+                .unwrap();
+        }
+        detector.archive_epoch(vec![233, 234]);
+        detector.archive_epochs(&[235, 236], 235);
+        assert_eq!(detector.get_epoch_watermark(), 234);
+        assert!(detector.epoch_history.get(&233).is_none());
+        assert!(detector.epoch_history.get(&234).is_none());
+        assert!(detector.epoch_history.get(&235).unwrap().is_none());
+        assert!(detector.epoch_history.get(&236).unwrap().is_none());
+        assert!(detector.epoch_history.get(&237).unwrap().is_some());
+
+        // A run starting at epoch 0 does not move the watermark.
+        let detector = ConflictDetector::default();
+        detector.archive_epochs(&[1, 2], 0);
+        assert_eq!(detector.get_epoch_watermark(), HummockEpoch::MIN);
+        assert!(detector.epoch_history.get(&1).unwrap().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_archive_epochs_after_first_epoch() {
+        let detector = ConflictDetector::default();
+        detector.archive_epochs(&[233, 234], 234);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_below_epoch_watermark() {
+        let detector = ConflictDetector::default();
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                233,
+            )
+            .unwrap();
+        detector.set_watermark(233);
+        detector
+            .check_conflict_and_track_write_batch(
+                once((Bytes::from("key1"), HummockValue::Delete))
+                    .collect_vec()
+                    .as_slice(),
+                232,
+            )
+            .unwrap();
     }
 }
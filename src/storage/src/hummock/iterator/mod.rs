@@ -40,6 +40,7 @@ use crate::hummock::local_version::pinned_version::PinnedVersion;
 use crate::hummock::shared_buffer::shared_buffer_batch::SharedBufferBatchIterator;
 use crate::hummock::shared_buffer::SharedBufferIteratorType;
 use crate::hummock::{BackwardSstableIterator, SstableIterator, SstableIteratorType};
+pub use crate::store::DirectionEnum;
 
 #[cfg(any(test, feature = "test"))]
 pub mod test_utils;
@@ -311,12 +312,6 @@ impl<I: HummockIterator> HummockIterator for Box<I> {
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-pub enum DirectionEnum {
-    Forward,
-    Backward,
-}
-
 pub trait HummockIteratorDirection: Sync + Send + 'static {
     fn direction() -> DirectionEnum;
 }
@@ -427,6 +422,14 @@ impl DirectedUserIterator {
         }
     }
 
+    #[inline(always)]
+    pub fn direction(&self) -> DirectionEnum {
+        match self {
+            Self::Forward(_) => DirectionEnum::Forward,
+            Self::Backward(_) => DirectionEnum::Backward,
+        }
+    }
+
     pub fn collect_local_statistic(&self, stats: &mut StoreLocalStatistic) {
         match self {
             DirectedUserIterator::Forward(iter) => iter.collect_local_statistic(stats),
@@ -14,7 +14,7 @@
 
 use std::cmp::Ordering;
 use std::future::Future;
-use std::ops::Bound::{Excluded, Included};
+use std::ops::Bound::{Excluded, Included, Unbounded};
 use std::ops::RangeBounds;
 use std::sync::Arc;
 
@@ -23,9 +23,10 @@ use bytes::Bytes;
 use itertools::Itertools;
 use minitrace::future::FutureExt;
 use minitrace::Span;
+use risingwave_common::catalog::TableId;
 use risingwave_common::util::epoch::INVALID_EPOCH;
-use risingwave_hummock_sdk::key::{key_with_epoch, next_key, user_key};
-use risingwave_hummock_sdk::{can_concat, HummockReadEpoch};
+use risingwave_hummock_sdk::key::{key_with_epoch, next_key, range_of_prefix, user_key};
+use risingwave_hummock_sdk::{can_concat, HummockEpoch, HummockReadEpoch};
 use risingwave_pb::hummock::LevelType;
 use tracing::log::warn;
 
@@ -478,19 +479,36 @@ impl StateStore for HummockStorage {
 
     /// Writes a batch to storage. The batch should be:
     /// * Ordered. KV pairs will be directly written to the table, so it must be ordered.
-    /// * Locally unique. There should not be two or more operations on the same key in one write
-    ///   batch.
+    /// * Locally unique. If there are two or more operations on the same key in one write batch,
+    ///   only the last one takes effect.
     /// * Globally unique. The streaming operators should ensure that different operators won't
     ///   operate on the same key. The operator operating on one keyspace should always wait for all
     ///   changes to be committed before reading and writing new keys to the engine. That is because
     ///   that the table with lower epoch might be committed after a table with higher epoch has
     ///   been committed. If such case happens, the outcome is non-predictable.
+    ///
+    /// An empty batch is a no-op that returns a size of 0. It does not create a shared buffer for
+    /// the epoch, which is fine because syncing an epoch without any write is always allowed.
     fn ingest_batch(
         &self,
         kv_pairs: Vec<(Bytes, StorageValue)>,
         write_options: WriteOptions,
     ) -> Self::IngestBatchFuture<'_> {
         async move {
+            if kv_pairs.is_empty() {
+                return Ok(0);
+            }
+            // The batch is ordered, so operations on the same key are adjacent. Keep the last one.
+            let kv_pairs = kv_pairs
+                .into_iter()
+                .coalesce(|prev, cur| {
+                    if prev.0 == cur.0 {
+                        Ok(cur)
+                    } else {
+                        Err((prev, cur))
+                    }
+                })
+                .collect_vec();
             let epoch = write_options.epoch;
             let compaction_group_id = self.get_compaction_group_id(write_options.table_id).await?;
             // See comments in HummockStorage::iter_inner for details about using
@@ -627,6 +645,91 @@ impl StateStore for HummockStorage {
 }
 
 impl HummockStorage {
+    /// Returns the value of `key` in the table `table_id` as of `epoch`, e.g. to read a consistent
+    /// snapshot while newer epochs are being written. Fails if `epoch` is older than the safe epoch
+    /// of the current version, as the data of that epoch may have been compacted away.
+    pub async fn get_at_epoch(
+        &self,
+        key: &[u8],
+        epoch: HummockEpoch,
+        table_id: TableId,
+    ) -> StorageResult<Option<Bytes>> {
+        let read_options = ReadOptions {
+            epoch,
+            table_id,
+            retention_seconds: None,
+        };
+        self.get(key, true, read_options).await
+    }
+
+    /// Returns the number of keys starting with `prefix`, based on a snapshot corresponding to
+    /// the epoch in `read_options`. Unlike draining [`StateStore::iter`], keys and values are never
+    /// copied out of the underlying iterator.
+    pub async fn count_prefix(
+        &self,
+        prefix: &[u8],
+        read_options: ReadOptions,
+    ) -> StorageResult<usize> {
+        self.iter_with_upper(prefix, &next_key(prefix), read_options)
+            .await?
+            .count()
+            .await
+    }
+
+    /// Returns an iterator over the keys starting with `prefix`, based on a snapshot corresponding
+    /// to the epoch in `read_options`. `upper` must be `next_key(prefix)`, the exclusive upper
+    /// bound of the prefix, which is empty if there is none.
+    ///
+    /// Unlike [`StateStore::iter`] over `range_of_prefix(prefix)`, the bound is not computed on
+    /// every call. An operator that repeatedly scans the same prefix, e.g. the state of one group,
+    /// can compute it once with `next_key` and keep it next to the prefix for all later scans.
+    pub async fn iter_with_upper(
+        &self,
+        prefix: &[u8],
+        upper: &[u8],
+        read_options: ReadOptions,
+    ) -> StorageResult<HummockStateStoreIter> {
+        debug_assert_eq!(upper, next_key(prefix).as_slice());
+        let start = if prefix.is_empty() {
+            Unbounded
+        } else {
+            Included(prefix)
+        };
+        let end = if upper.is_empty() {
+            Unbounded
+        } else {
+            Excluded(upper)
+        };
+        self.iter_inner::<_, _, ForwardIter>(None, (start, end), read_options)
+            .await
+    }
+
+    /// Deletes all keys starting with `prefix` by writing a tombstone for each of them in a single
+    /// batch, and returns the number of deleted keys. The keys are read from the snapshot at the
+    /// epoch to write, so this must not be mixed with other writes under `prefix` in that epoch.
+    pub async fn delete_prefix(
+        &self,
+        prefix: &[u8],
+        write_options: WriteOptions,
+    ) -> StorageResult<usize> {
+        let read_options = ReadOptions {
+            epoch: write_options.epoch,
+            table_id: write_options.table_id,
+            retention_seconds: None,
+        };
+        let kv_pairs = self
+            .scan(None, range_of_prefix(prefix), None, read_options)
+            .await?
+            .into_iter()
+            .map(|(key, _)| (key, StorageValue::new_delete()))
+            .collect_vec();
+        let deleted = kv_pairs.len();
+        if deleted > 0 {
+            self.ingest_batch(kv_pairs, write_options).await?;
+        }
+        Ok(deleted)
+    }
+
     #[cfg(any(test, feature = "test"))]
     pub async fn seal_and_sync_epoch(&self, epoch: u64) -> StorageResult<SyncResult> {
         self.seal_epoch(epoch, true);
@@ -637,11 +740,50 @@ impl HummockStorage {
 pub struct HummockStateStoreIter {
     inner: DirectedUserIterator,
     metrics: Arc<StateStoreMetrics>,
+    /// Whether the current entry of `inner` has been borrowed by [`Self::next_ref`], so it must be
+    /// skipped before reading the next one.
+    borrowed: bool,
 }
 
 impl HummockStateStoreIter {
     fn new(inner: DirectedUserIterator, metrics: Arc<StateStoreMetrics>) -> Self {
-        Self { inner, metrics }
+        Self {
+            inner,
+            metrics,
+            borrowed: false,
+        }
+    }
+
+    /// Like [`StateStoreIter::next`], but borrows the key and value from the underlying iterator
+    /// instead of copying them into [`Bytes`]. The returned slices are only valid until the next
+    /// call, so this suits consumers that process each entry and discard it right away.
+    pub async fn next_ref(&mut self) -> StorageResult<Option<(&[u8], &[u8])>> {
+        self.skip_borrowed().await?;
+        let iter = &self.inner;
+        if iter.is_valid() {
+            self.borrowed = true;
+            Ok(Some((iter.key(), iter.value())))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the key and value that the next call to [`StateStoreIter::next`] would return,
+    /// without advancing the iterator. If the last entry was borrowed by [`Self::next_ref`], it is
+    /// skipped first.
+    pub async fn peek(&mut self) -> StorageResult<Option<(&[u8], &[u8])>> {
+        self.skip_borrowed().await?;
+        let iter = &self.inner;
+        Ok(iter.is_valid().then(|| (iter.key(), iter.value())))
+    }
+
+    /// Advances past the entry last returned by [`Self::next_ref`], if any.
+    async fn skip_borrowed(&mut self) -> StorageResult<()> {
+        if self.borrowed {
+            self.borrowed = false;
+            self.inner.next().await?;
+        }
+        Ok(())
     }
 
     async fn collect(mut self, limit: Option<usize>) -> StorageResult<Vec<(Bytes, Bytes)>> {
@@ -657,6 +799,17 @@ impl HummockStateStoreIter {
         Ok(kvs)
     }
 
+    async fn count(mut self) -> StorageResult<usize> {
+        self.skip_borrowed().await?;
+        let iter = &mut self.inner;
+        let mut count = 0;
+        while iter.is_valid() {
+            count += 1;
+            iter.next().await?;
+        }
+        Ok(count)
+    }
+
     fn collect_local_statistic(&self, stats: &mut StoreLocalStatistic) {
         self.inner.collect_local_statistic(stats);
     }
@@ -671,6 +824,7 @@ impl StateStoreIter for HummockStateStoreIter {
 
     fn next(&mut self) -> Self::NextFuture<'_> {
         async move {
+            self.skip_borrowed().await?;
             let iter = &mut self.inner;
 
             if iter.is_valid() {
@@ -685,6 +839,10 @@ impl StateStoreIter for HummockStateStoreIter {
             }
         }
     }
+
+    fn direction(&self) -> Option<DirectionEnum> {
+        Some(self.inner.direction())
+    }
 }
 
 impl Drop for HummockStateStoreIter {
@@ -20,7 +20,7 @@ use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::key::{prefixed_range, table_prefix};
 
 use crate::error::StorageResult;
-use crate::store::{ReadOptions, WriteOptions};
+use crate::store::{DirectionEnum, ReadOptions, WriteOptions};
 use crate::write_batch::KeySpaceWriteBatch;
 use crate::{StateStore, StateStoreIter};
 
@@ -189,4 +189,8 @@ impl<I: StateStoreIter<Item = (Bytes, Bytes)>> StateStoreIter for StripPrefixIte
                 .map(|(key, value)| (key.slice(self.prefix_len..), value)))
         }
     }
+
+    fn direction(&self) -> Option<DirectionEnum> {
+        self.iter.direction()
+    }
 }
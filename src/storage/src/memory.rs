@@ -384,6 +384,11 @@ impl StateStoreIter for MemoryStateStoreIter {
             Ok(None)
         }
     }
+
+    fn direction(&self) -> Option<DirectionEnum> {
+        // `backward_iter` is not supported by the memory state store.
+        Some(DirectionEnum::Forward)
+    }
 }
 
 #[cfg(test)]
@@ -332,6 +332,10 @@ where
             Ok(pair)
         }
     }
+
+    fn direction(&self) -> Option<DirectionEnum> {
+        self.inner.direction()
+    }
 }
 
 impl<I> Drop for MonitoredStateStoreIter<I> {
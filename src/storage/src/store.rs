@@ -211,11 +211,24 @@ pub trait StateStore: Send + Sync + 'static + Clone {
     }
 }
 
+/// The order in which an iterator yields keys.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DirectionEnum {
+    Forward,
+    Backward,
+}
+
 pub trait StateStoreIter: Send + 'static {
     type Item;
     type NextFuture<'a>: Future<Output = StorageResult<Option<Self::Item>>> + Send;
 
     fn next(&mut self) -> Self::NextFuture<'_>;
+
+    /// Returns the order in which `next` yields keys, or `None` if the iterator does not know it.
+    /// Generic code can use this to assert the ordering it relies on.
+    fn direction(&self) -> Option<DirectionEnum> {
+        None
+    }
 }
 
 #[derive(Default, Clone)]
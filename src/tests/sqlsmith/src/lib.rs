@@ -22,8 +22,8 @@ use rand::Rng;
 use risingwave_common::types::DataTypeName;
 use risingwave_frontend::bind_data_type;
 use risingwave_sqlparser::ast::{
-    BinaryOperator, ColumnDef, Cte, Expr, Ident, Join, JoinConstraint, JoinOperator, ObjectName,
-    OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableWithJoins, With,
+    BinaryOperator, ColumnDef, Cte, Distinct, Expr, Ident, Join, JoinConstraint, JoinOperator,
+    ObjectName, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableWithJoins, With,
 };
 use risingwave_sqlparser::parser::Parser;
 
@@ -292,7 +292,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let having = self.gen_having(!group_by.is_empty());
         let (select_list, schema) = self.gen_select_list();
         let select = Select {
-            distinct: false,
+            distinct: Distinct::All,
             projection: select_list,
             from,
             lateral_views: vec![],